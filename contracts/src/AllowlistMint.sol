@@ -0,0 +1,57 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.27;
+
+import {ERC20} from "@openzeppelin-contracts-5.1.0/token/ERC20/ERC20.sol";
+import {ECDSA} from "@openzeppelin-contracts-5.1.0/utils/cryptography/ECDSA.sol";
+import {EIP712} from "@openzeppelin-contracts-5.1.0/utils/cryptography/EIP712.sol";
+import {MerkleProof} from "@openzeppelin-contracts-5.1.0/utils/cryptography/MerkleProof.sol";
+
+/**
+ * @title Allowlist Mint Token
+ * @notice Sample token gated by a merkle allowlist or by vouchers signed off-chain.
+ * @dev Leaves follow the OpenZeppelin `StandardMerkleTree` encoding of `(address, uint256)`.
+ */
+contract AllowlistMint is ERC20, EIP712 {
+    bytes32 public constant VOUCHER_TYPEHASH = keccak256("MintVoucher(address minter,uint256 amount)");
+
+    bytes32 public immutable merkleRoot;
+    address public immutable voucherSigner;
+
+    mapping(address => bool) private hasMinted;
+
+    constructor(bytes32 root, address signer) ERC20("Allowlist", "ALW") EIP712("AllowlistMint", "1") {
+        merkleRoot = root;
+        voucherSigner = signer;
+    }
+
+    /**
+     * @dev Mints `amount` tokens to an address of the allowlist.
+     * @param amount The amount allotted to `msg.sender` in the allowlist.
+     * @param proof The merkle proof of the `(msg.sender, amount)` leaf.
+     */
+    function allowlistMint(uint256 amount, bytes32[] calldata proof) external {
+        bytes32 leaf = keccak256(bytes.concat(keccak256(abi.encode(msg.sender, amount))));
+        require(MerkleProof.verifyCalldata(proof, merkleRoot, leaf), "Invalid proof");
+
+        _claim(amount);
+    }
+
+    /**
+     * @dev Mints `amount` tokens with a voucher signed by `voucherSigner`.
+     * @param amount The amount of the voucher.
+     * @param signature The EIP-712 signature of `MintVoucher(msg.sender, amount)`.
+     */
+    function voucherMint(uint256 amount, bytes calldata signature) external {
+        bytes32 digest = _hashTypedDataV4(keccak256(abi.encode(VOUCHER_TYPEHASH, msg.sender, amount)));
+        require(ECDSA.recover(digest, signature) == voucherSigner, "Invalid voucher");
+
+        _claim(amount);
+    }
+
+    function _claim(uint256 amount) private {
+        require(!hasMinted[msg.sender], "Address has already minted");
+
+        hasMinted[msg.sender] = true;
+        _mint(msg.sender, amount);
+    }
+}
@@ -0,0 +1,48 @@
+// SPDX-License-Identifier: UNLICENSED
+pragma solidity ^0.8.27;
+
+import {Test} from "forge-std/Test.sol";
+import {AllowlistMint} from "../src/AllowlistMint.sol";
+
+contract AllowlistMintTest is Test {
+    AllowlistMint public allowlistMint;
+
+    address alice = makeAddr("Alice");
+    uint256 signerKey = 0xA11CE;
+
+    function setUp() public {
+        // single-leaf tree: the root is the leaf and the proof is empty
+        bytes32 root = keccak256(bytes.concat(keccak256(abi.encode(alice, 5))));
+        allowlistMint = new AllowlistMint(root, vm.addr(signerKey));
+    }
+
+    function test_allowlistMint() public {
+        vm.prank(alice);
+        allowlistMint.allowlistMint(5, new bytes32[](0));
+        assertEq(allowlistMint.balanceOf(alice), 5);
+    }
+
+    function testFail_allowlistMintWrongAmount() public {
+        vm.prank(alice);
+        allowlistMint.allowlistMint(6, new bytes32[](0));
+    }
+
+    function test_voucherMint() public {
+        bytes32 structHash = keccak256(abi.encode(allowlistMint.VOUCHER_TYPEHASH(), alice, 7));
+        bytes32 domainSeparator = keccak256(
+            abi.encode(
+                keccak256("EIP712Domain(string name,string version,uint256 chainId,address verifyingContract)"),
+                keccak256("AllowlistMint"),
+                keccak256("1"),
+                block.chainid,
+                address(allowlistMint)
+            )
+        );
+        bytes32 digest = keccak256(abi.encodePacked("\x19\x01", domainSeparator, structHash));
+        (uint8 v, bytes32 r, bytes32 s) = vm.sign(signerKey, digest);
+
+        vm.prank(alice);
+        allowlistMint.voucherMint(7, abi.encodePacked(r, s, v));
+        assertEq(allowlistMint.balanceOf(alice), 7);
+    }
+}
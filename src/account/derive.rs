@@ -0,0 +1,189 @@
+use super::SecretString;
+use alloy::{
+    primitives::Address,
+    signers::{
+        local::{
+            coins_bip39::{English, Wordlist},
+            PrivateKeySigner,
+        },
+        utils::secret_key_to_address,
+    },
+};
+use coins_bip32::{
+    prelude::SigningKey,
+    xkeys::{Parent, XPriv},
+};
+use eyre::{eyre, Result};
+use pbkdf2::pbkdf2_hmac;
+use sha2::{Digest, Sha256, Sha512};
+use zeroize::Zeroizing;
+
+/// The BIP44 path of the first Ethereum account.
+pub(crate) const ACCOUNT_PATH: &str = "m/44'/60'/0'";
+
+/// The BIP44 path of the external chain of the first Ethereum account.
+///
+/// Every account generated by this crate is a direct, non-hardened child of this node.
+pub(crate) const ACCOUNT_CHAIN_PATH: &str = "m/44'/60'/0'/0";
+
+/// The number of PBKDF2 rounds used to stretch a BIP39 mnemonic into a seed.
+const SEED_ROUNDS: u32 = 2048;
+
+/// The prefix of the PBKDF2 salt, followed by the optional passphrase.
+const SEED_SALT_PREFIX: &str = "mnemonic";
+
+/// Derives the extended private key at `path` from a mnemonic phrase.
+///
+/// The phrase is validated against the English wordlist, then stretched into a seed that is
+/// wiped as soon as the root key has been computed. Unlike `MnemonicBuilder`, no owned copy of
+/// the phrase, its entropy or the passphrase outlives this call.
+///
+/// # Arguments
+///
+/// * `mnemonic` - A BIP39 mnemonic phrase string
+/// * `passphrase` - The optional BIP39 passphrase, the "25th word"
+/// * `path` - The derivation path of the key
+///
+/// # Returns
+///
+/// * `Result<XPriv>` - The extended private key on success
+pub(crate) fn derive_key(
+    mnemonic: &str,
+    passphrase: Option<&SecretString>,
+    path: &str,
+) -> Result<XPriv> {
+    let phrase = Zeroizing::new(mnemonic.split_whitespace().collect::<Vec<_>>().join(" "));
+    validate_phrase(&phrase)?;
+
+    let salt = Zeroizing::new(format!(
+        "{SEED_SALT_PREFIX}{}",
+        passphrase.map_or("", SecretString::expose)
+    ));
+    let mut seed = Zeroizing::new([0u8; 64]);
+    pbkdf2_hmac::<Sha512>(
+        phrase.as_bytes(),
+        salt.as_bytes(),
+        SEED_ROUNDS,
+        seed.as_mut(),
+    );
+
+    Ok(XPriv::root_from_seed(seed.as_slice(), None)?.derive_path(path)?)
+}
+
+/// Checks the words and the checksum of a normalized phrase.
+///
+/// The entropy is rebuilt in a zeroizing buffer, so no copy of it is left behind.
+fn validate_phrase(phrase: &str) -> Result<()> {
+    let word_count = phrase.split(' ').count();
+    if ![12, 15, 18, 21, 24].contains(&word_count) {
+        return Err(eyre!(
+            "a mnemonic has 12, 15, 18, 21 or 24 words, got {word_count}"
+        ));
+    }
+
+    // 11 bits per word: the entropy followed by one checksum bit per 32 bits of entropy
+    let mut bits = Zeroizing::new(vec![0u8; (word_count * 11).div_ceil(8)]);
+    for (position, word) in phrase.split(' ').enumerate() {
+        let index = English::get_index(word).map_err(|_| eyre!("invalid mnemonic word"))?;
+        for bit in 0..11 {
+            if index >> (10 - bit) & 1 == 1 {
+                let offset = position * 11 + bit;
+                bits[offset / 8] |= 0x80 >> (offset % 8);
+            }
+        }
+    }
+
+    let entropy_len = word_count * 4 / 3;
+    let checksum_bits = word_count / 3;
+    let checksum = bits[entropy_len] >> (8 - checksum_bits);
+    let expected = Sha256::digest(&bits[..entropy_len])[0] >> (8 - checksum_bits);
+    if checksum != expected {
+        return Err(eyre!("invalid mnemonic checksum"));
+    }
+
+    Ok(())
+}
+
+/// Derives Ethereum accounts below the default BIP44 account chain.
+///
+/// The mnemonic is stretched into a seed only once; every index is derived from the cached
+/// chain node, which is orders of magnitude cheaper than rebuilding the path from the phrase.
+/// Derived signing keys are wiped from memory when dropped.
+pub(crate) struct Deriver {
+    chain: XPriv,
+}
+
+impl Deriver {
+    /// Creates a new `Deriver` instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `mnemonic` - A BIP39 mnemonic phrase string
+    /// * `passphrase` - The optional BIP39 passphrase
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Self>` - A deriver positioned at the account chain node on success
+    pub(crate) fn new(mnemonic: &str, passphrase: Option<&SecretString>) -> Result<Self> {
+        let chain = derive_key(mnemonic, passphrase, ACCOUNT_CHAIN_PATH)?;
+
+        Ok(Self { chain })
+    }
+
+    /// Derives the signing key at the given index.
+    fn key(&self, index: u32) -> Result<SigningKey> {
+        let child = self.chain.derive_child(index)?;
+        let key: &SigningKey = child.as_ref();
+
+        Ok(key.clone())
+    }
+
+    /// Derives the private key signer at the given index.
+    pub(crate) fn signer(&self, index: u32) -> Result<PrivateKeySigner> {
+        Ok(PrivateKeySigner::from_signing_key(self.key(index)?))
+    }
+
+    /// Derives only the address at the given index, dropping the private key immediately.
+    pub(crate) fn address(&self, index: u32) -> Result<Address> {
+        Ok(secret_key_to_address(&self.key(index)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy::hex;
+
+    // the 24 word vector of the reference implementation, whose seed uses the passphrase `TREZOR`
+    const PHRASE: &str = "void come effort suffer camp survey warrior heavy shoot primary clutch crush open amazing screen patrol group space point ten exist slush involve unfold";
+    const SEED: &str = "01f5bced59dec48e362f2c45b5de68b9fd6c92c6634f44d6d40aab69056506f0e35524a518034ddc1192e1dacd32c1ed3eaa3c3b131c88ed8e7e54c49a5d0998";
+
+    #[test]
+    fn test_derive_key_with_passphrase() {
+        let seed = hex::decode(SEED).unwrap();
+        let expected = XPriv::root_from_seed(&seed, None)
+            .unwrap()
+            .derive_path(ACCOUNT_PATH)
+            .unwrap();
+
+        let passphrase = SecretString::from("TREZOR");
+        let key = derive_key(PHRASE, Some(&passphrase), ACCOUNT_PATH).unwrap();
+        assert_eq!(key.verify_key(), expected.verify_key());
+
+        // a passphrase leads to another wallet
+        let key = derive_key(PHRASE, None, ACCOUNT_PATH).unwrap();
+        assert_ne!(key.verify_key(), expected.verify_key());
+    }
+
+    #[test]
+    fn test_validate_phrase() {
+        validate_phrase(PHRASE).unwrap();
+        validate_phrase("test test test test test test test test test test test junk").unwrap();
+
+        // a wrong last word breaks the checksum
+        let phrase = PHRASE.replace("unfold", "void");
+        assert!(validate_phrase(&phrase).is_err());
+        assert!(validate_phrase("test test test").is_err());
+        assert!(validate_phrase(&PHRASE.replace("void", "notaword")).is_err());
+    }
+}
@@ -1,11 +1,14 @@
-use alloy::signers::local::{coins_bip39::English, MnemonicBuilder, PrivateKeySigner};
+use super::{derive::Deriver, NoProgress, Progress, SecretString};
+use alloy::signers::local::PrivateKeySigner;
 use eyre::Result;
-use indicatif::{ProgressBar, ProgressStyle};
 use rayon::prelude::*;
-use std::sync::Mutex;
+use std::time::Instant;
+use tracing::{debug, instrument};
 
 /// Generates multiple Ethereum accounts from a single mnemonic phrase.
 ///
+/// The phrase is only read once to derive the account chain node; no copies of it are kept.
+///
 /// # Arguments
 ///
 /// * `mnemonic` - A BIP39 mnemonic phrase string
@@ -14,39 +17,99 @@ use std::sync::Mutex;
 ///
 /// # Returns
 ///
-/// * `Result<Vec<PrivateKeySigner>>` - A vector of private key signers on success
+/// * `Result<Vec<PrivateKeySigner>>` - A vector of private key signers in index order on success
 pub fn generate_accounts(
     mnemonic: &str,
     start_index: u32,
     end_index: u32,
 ) -> Result<Vec<PrivateKeySigner>> {
-    let account_count = end_index - start_index;
+    generate_accounts_with_progress(mnemonic, start_index, end_index, &NoProgress)
+}
 
-    // set process bar
-    let pb = ProgressBar::new(account_count as u64);
-    pb.set_style(ProgressStyle::default_bar()
-        .template("{spinner:.green} [{elapsed_precise}] [{bar:40.cyan/blue}] {pos}/{len} accounts generation ({percent}%) ETA: {eta_precise}")?
-        .progress_chars("=>-"));
+/// Generates multiple Ethereum accounts from a mnemonic phrase protected by a BIP39 passphrase.
+///
+/// The passphrase is salted into the seed, so each passphrase leads to a different set of
+/// accounts; an empty passphrase is the same as none.
+///
+/// # Arguments
+///
+/// * `mnemonic` - A BIP39 mnemonic phrase string
+/// * `passphrase` - The BIP39 passphrase, the "25th word"
+/// * `start_index` - The starting index for the derivation path
+/// * `end_index` - The ending index for the derivation path (exclusive)
+///
+/// # Returns
+///
+/// * `Result<Vec<PrivateKeySigner>>` - A vector of private key signers in index order on success
+pub fn generate_accounts_with_passphrase(
+    mnemonic: &str,
+    passphrase: &SecretString,
+    start_index: u32,
+    end_index: u32,
+) -> Result<Vec<PrivateKeySigner>> {
+    generate(
+        mnemonic,
+        Some(passphrase),
+        start_index,
+        end_index,
+        &NoProgress,
+    )
+}
 
-    // preallocate a vector and create a structure for storing the results
-    let accounts = Mutex::new(Vec::with_capacity(account_count as usize));
+/// Generates multiple Ethereum accounts from a single mnemonic phrase, reporting the progress.
+///
+/// # Arguments
+///
+/// * `mnemonic` - A BIP39 mnemonic phrase string
+/// * `start_index` - The starting index for the derivation path
+/// * `end_index` - The ending index for the derivation path (exclusive)
+/// * `progress` - Receives one step per generated account, e.g. a terminal bar from
+///   [`progress_bar`](super::progress_bar).
+///
+/// # Returns
+///
+/// * `Result<Vec<PrivateKeySigner>>` - A vector of private key signers in index order on success
+pub fn generate_accounts_with_progress(
+    mnemonic: &str,
+    start_index: u32,
+    end_index: u32,
+    progress: &dyn Progress,
+) -> Result<Vec<PrivateKeySigner>> {
+    generate(mnemonic, None, start_index, end_index, progress)
+}
 
-    // generate initial builder
-    let builder = MnemonicBuilder::<English>::default().phrase(mnemonic);
+/// Derives the accounts of a range of indices in parallel.
+#[instrument(skip(mnemonic, passphrase, progress))]
+fn generate(
+    mnemonic: &str,
+    passphrase: Option<&SecretString>,
+    start_index: u32,
+    end_index: u32,
+    progress: &dyn Progress,
+) -> Result<Vec<PrivateKeySigner>> {
+    let started = Instant::now();
+    let account_count = end_index.saturating_sub(start_index);
+    progress.start(account_count as u64);
+
+    // derive the account chain node once
+    let deriver = Deriver::new(mnemonic, passphrase)?;
 
     // parallel account generation
-    (start_index..end_index)
+    let accounts = (start_index..end_index)
         .into_par_iter()
-        .try_for_each(|index| -> Result<()> {
-            let wallet = builder.clone().index(index)?.build()?;
-            accounts.lock().unwrap().push(wallet);
-            pb.inc(1);
-            Ok(())
-        })?;
+        .map(|index| {
+            let wallet = deriver.signer(index);
+            progress.inc(1);
+            wallet
+        })
+        .collect::<Result<Vec<_>>>()?;
 
-    // finish process bar and fetch result
-    pb.finish_with_message("Account generation completed successfully!");
-    let accounts = accounts.into_inner()?;
+    progress.finish();
+    debug!(
+        accounts = accounts.len(),
+        elapsed_ms = started.elapsed().as_millis() as u64,
+        "accounts generated"
+    );
 
     Ok(accounts)
 }
@@ -54,6 +117,7 @@ pub fn generate_accounts(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::sync::atomic::{AtomicU64, Ordering};
 
     const PHRASE: &str = "test test test test test test test test test test test junk";
 
@@ -66,6 +130,42 @@ mod tests {
         assert_eq!(accounts.unwrap().len() as u32, end_index - start_index);
     }
 
+    #[test]
+    fn test_accounts_generation_progress() {
+        #[derive(Default)]
+        struct Counter(AtomicU64, AtomicU64);
+
+        impl Progress for Counter {
+            fn start(&self, len: u64) {
+                self.0.store(len, Ordering::Relaxed);
+            }
+
+            fn inc(&self, delta: u64) {
+                self.1.fetch_add(delta, Ordering::Relaxed);
+            }
+
+            fn finish(&self) {}
+        }
+
+        let counter = Counter::default();
+        generate_accounts_with_progress(PHRASE, 3, 8, &counter).unwrap();
+
+        assert_eq!(counter.0.load(Ordering::Relaxed), 5);
+        assert_eq!(counter.1.load(Ordering::Relaxed), 5);
+    }
+
+    #[test]
+    fn test_accounts_generation_with_passphrase() {
+        let accounts = generate_accounts(PHRASE, 0, 2).unwrap();
+        let protected =
+            generate_accounts_with_passphrase(PHRASE, &SecretString::from("TREZOR"), 0, 2).unwrap();
+        assert_ne!(accounts[0].address(), protected[0].address());
+
+        let empty =
+            generate_accounts_with_passphrase(PHRASE, &SecretString::from(""), 0, 2).unwrap();
+        assert_eq!(accounts[0].address(), empty[0].address());
+    }
+
     #[test]
     fn test_accounts_generation() {
         let (start_index, end_index) = (0u32, 1u32);
@@ -1,2 +1,22 @@
+mod derive;
+
 mod generate;
-pub use generate::generate_accounts;
+pub use generate::{
+    generate_accounts, generate_accounts_with_passphrase, generate_accounts_with_progress,
+};
+
+mod progress;
+#[cfg(feature = "progress-bar")]
+pub use progress::progress_bar;
+pub use progress::{NoProgress, Progress};
+
+mod secret;
+pub use secret::SecretString;
+
+mod stream;
+pub use stream::{
+    generate_addresses, stream_accounts, stream_addresses, AccountChunks, BlockingChunks,
+};
+
+mod watch;
+pub use watch::{account_xpub, WatchOnlyWallet, BALANCE_CONCURRENCY};
@@ -0,0 +1,64 @@
+/// Receives the progress of a long-running operation, such as account generation.
+///
+/// Library code never draws to the terminal by itself: pass a [`NoProgress`] to stay silent, a
+/// terminal bar from [`progress_bar`] (with the `progress-bar` feature), or any type reporting
+/// to a UI of your own.
+pub trait Progress: Send + Sync {
+    /// Called once before the operation starts, with the number of steps it has.
+    fn start(&self, len: u64);
+
+    /// Called after `delta` more steps completed.
+    fn inc(&self, delta: u64);
+
+    /// Called once after the operation completed.
+    fn finish(&self);
+}
+
+/// A [`Progress`] ignoring every update.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoProgress;
+
+impl Progress for NoProgress {
+    fn start(&self, _len: u64) {}
+
+    fn inc(&self, _delta: u64) {}
+
+    fn finish(&self) {}
+}
+
+#[cfg(feature = "progress-bar")]
+impl Progress for indicatif::ProgressBar {
+    fn start(&self, len: u64) {
+        self.set_length(len);
+    }
+
+    fn inc(&self, delta: u64) {
+        indicatif::ProgressBar::inc(self, delta);
+    }
+
+    fn finish(&self) {
+        self.finish_with_message("done");
+    }
+}
+
+/// Creates a terminal progress bar for an operation over `items`, e.g. `"accounts generation"`.
+///
+/// # Arguments
+///
+/// * `items` - What the bar counts, shown next to it.
+///
+/// # Returns
+///
+/// * `indicatif::ProgressBar` - A hidden-length bar; [`Progress::start`] sets its length.
+#[cfg(feature = "progress-bar")]
+pub fn progress_bar(items: &str) -> indicatif::ProgressBar {
+    let template = format!(
+        "{{spinner:.green}} [{{elapsed_precise}}] [{{bar:40.cyan/blue}}] {{pos}}/{{len}} {items} ({{percent}}%) ETA: {{eta_precise}}"
+    );
+    let style = indicatif::ProgressStyle::default_bar()
+        .template(&template)
+        .unwrap_or_else(|_| indicatif::ProgressStyle::default_bar())
+        .progress_chars("=>-");
+
+    indicatif::ProgressBar::new(0).with_style(style)
+}
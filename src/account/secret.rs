@@ -0,0 +1,126 @@
+use alloy::{hex, signers::local::PrivateKeySigner};
+use eyre::{eyre, Result};
+use std::{env, fmt, fs, path::Path, str::FromStr};
+use zeroize::Zeroizing;
+
+/// A string holding secret material such as a mnemonic phrase, a passphrase or a private key.
+///
+/// The content is wiped from memory when the value is dropped and is never printed by
+/// [`Debug`](fmt::Debug).
+#[derive(Clone, PartialEq, Eq)]
+pub struct SecretString(Zeroizing<String>);
+
+impl SecretString {
+    /// Creates a new `SecretString` instance, taking ownership of the secret.
+    ///
+    /// # Arguments
+    ///
+    /// * `secret` - The secret to protect
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - A new `SecretString` instance.
+    pub fn new(secret: String) -> Self {
+        Self(Zeroizing::new(secret))
+    }
+
+    /// Reads a secret from an environment variable.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the environment variable
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Self>` - The trimmed secret on success
+    pub fn from_env(name: &str) -> Result<Self> {
+        let value = Zeroizing::new(
+            env::var(name).map_err(|e| eyre!("failed to read secret from `{name}`: {e}"))?,
+        );
+
+        Ok(Self::from(value.trim()))
+    }
+
+    /// Reads a secret from a file.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the file holding the secret
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Self>` - The trimmed secret on success
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref();
+        let content = Zeroizing::new(
+            fs::read_to_string(path)
+                .map_err(|e| eyre!("failed to read secret from `{}`: {e}", path.display()))?,
+        );
+
+        Ok(Self::from(content.trim()))
+    }
+
+    /// Exposes the secret.
+    ///
+    /// Callers must not copy the returned value into containers that outlive it.
+    pub fn expose(&self) -> &str {
+        &self.0
+    }
+
+    /// Parses the secret as a hex encoded private key.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<PrivateKeySigner>` - The private key signer on success
+    pub fn to_signer(&self) -> Result<PrivateKeySigner> {
+        let bytes = Zeroizing::new(
+            hex::decode(self.expose()).map_err(|_| eyre!("private key is not valid hex"))?,
+        );
+
+        PrivateKeySigner::from_slice(&bytes).map_err(|_| eyre!("invalid private key"))
+    }
+}
+
+impl From<&str> for SecretString {
+    fn from(secret: &str) -> Self {
+        Self::new(secret.to_owned())
+    }
+}
+
+impl FromStr for SecretString {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self::from(s))
+    }
+}
+
+impl fmt::Debug for SecretString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("SecretString(<redacted>)")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PRIVATE_KEY: &str = "0xac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80";
+
+    #[test]
+    fn test_debug_is_redacted() {
+        let secret = SecretString::from(PRIVATE_KEY);
+        assert_eq!(format!("{secret:?}"), "SecretString(<redacted>)");
+    }
+
+    #[test]
+    fn test_to_signer() {
+        let signer = SecretString::from(PRIVATE_KEY).to_signer().unwrap();
+        assert_eq!(
+            signer.address().to_string(),
+            "0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266"
+        );
+
+        assert!(SecretString::from("not a key").to_signer().is_err());
+    }
+}
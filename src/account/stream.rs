@@ -0,0 +1,237 @@
+use super::derive::Deriver;
+use alloy::{primitives::Address, signers::local::PrivateKeySigner};
+use eyre::{eyre, Result};
+use futures::Stream;
+use rayon::prelude::*;
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+    thread,
+};
+use tokio::sync::mpsc::{self, Receiver};
+use tracing::instrument;
+
+/// The number of derived chunks buffered ahead of the consumer.
+const CHUNK_BUFFER: usize = 2;
+
+/// An ordered sequence of account chunks derived in the background.
+///
+/// Chunks are derived in parallel on a dedicated thread and handed over through a bounded
+/// channel, so at most a couple of chunks are held in memory and the consumer can start working
+/// on the first chunk while the rest of the range is still being derived. Dropping the value
+/// stops the derivation.
+///
+/// `AccountChunks` is an async [`Stream`] to be polled from within a Tokio runtime; synchronous
+/// code can consume it as a blocking iterator through [`IntoIterator`].
+pub struct AccountChunks<T> {
+    receiver: Receiver<Result<Vec<T>>>,
+}
+
+impl<T: Send + 'static> AccountChunks<T> {
+    /// Spawns the background derivation of `start_index..end_index` in chunks of `chunk_size`.
+    fn spawn<F>(
+        mnemonic: &str,
+        start_index: u32,
+        end_index: u32,
+        chunk_size: usize,
+        derive: F,
+    ) -> Result<Self>
+    where
+        F: Fn(&Deriver, u32) -> Result<T> + Send + Sync + 'static,
+    {
+        if chunk_size == 0 {
+            return Err(eyre!("chunk size must be greater than zero"));
+        }
+
+        let deriver = Deriver::new(mnemonic, None)?;
+        let chunk_size = u32::try_from(chunk_size).unwrap_or(u32::MAX);
+        let (sender, receiver) = mpsc::channel(CHUNK_BUFFER);
+
+        thread::spawn(move || {
+            let mut chunk_start = start_index;
+            while chunk_start < end_index {
+                let chunk_end = end_index.min(chunk_start.saturating_add(chunk_size));
+
+                // par_iter keeps the output in index order
+                let chunk = (chunk_start..chunk_end)
+                    .into_par_iter()
+                    .map(|index| derive(&deriver, index))
+                    .collect::<Result<Vec<T>>>();
+
+                let failed = chunk.is_err();
+                // stop early once the consumer has gone away or derivation failed
+                if sender.blocking_send(chunk).is_err() || failed {
+                    break;
+                }
+                chunk_start = chunk_end;
+            }
+        });
+
+        Ok(Self { receiver })
+    }
+}
+
+impl<T> IntoIterator for AccountChunks<T> {
+    type Item = Result<Vec<T>>;
+    type IntoIter = BlockingChunks<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        BlockingChunks { chunks: self }
+    }
+}
+
+/// A blocking iterator over [`AccountChunks`].
+///
+/// # Panics
+///
+/// Advancing the iterator panics when done from within an asynchronous execution context; use
+/// the [`Stream`] implementation of [`AccountChunks`] there instead.
+pub struct BlockingChunks<T> {
+    chunks: AccountChunks<T>,
+}
+
+impl<T> Iterator for BlockingChunks<T> {
+    type Item = Result<Vec<T>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.chunks.receiver.blocking_recv()
+    }
+}
+
+impl<T> Stream for AccountChunks<T> {
+    type Item = Result<Vec<T>>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.receiver.poll_recv(cx)
+    }
+}
+
+/// Streams Ethereum accounts derived from a single mnemonic phrase in ordered chunks.
+///
+/// # Arguments
+///
+/// * `mnemonic` - A BIP39 mnemonic phrase string
+/// * `start_index` - The starting index for the derivation path
+/// * `end_index` - The ending index for the derivation path (exclusive)
+/// * `chunk_size` - The maximum number of accounts in each chunk
+///
+/// # Returns
+///
+/// * `Result<AccountChunks<PrivateKeySigner>>` - The chunks of private key signers, in index order
+#[instrument(level = "debug", skip(mnemonic))]
+pub fn stream_accounts(
+    mnemonic: &str,
+    start_index: u32,
+    end_index: u32,
+    chunk_size: usize,
+) -> Result<AccountChunks<PrivateKeySigner>> {
+    AccountChunks::spawn(
+        mnemonic,
+        start_index,
+        end_index,
+        chunk_size,
+        Deriver::signer,
+    )
+}
+
+/// Streams Ethereum addresses derived from a single mnemonic phrase in ordered chunks.
+///
+/// Private keys are discarded as soon as their address is computed, which keeps the memory
+/// footprint to 20 bytes per account.
+///
+/// # Arguments
+///
+/// * `mnemonic` - A BIP39 mnemonic phrase string
+/// * `start_index` - The starting index for the derivation path
+/// * `end_index` - The ending index for the derivation path (exclusive)
+/// * `chunk_size` - The maximum number of addresses in each chunk
+///
+/// # Returns
+///
+/// * `Result<AccountChunks<Address>>` - The chunks of addresses, in index order
+#[instrument(level = "debug", skip(mnemonic))]
+pub fn stream_addresses(
+    mnemonic: &str,
+    start_index: u32,
+    end_index: u32,
+    chunk_size: usize,
+) -> Result<AccountChunks<Address>> {
+    AccountChunks::spawn(
+        mnemonic,
+        start_index,
+        end_index,
+        chunk_size,
+        Deriver::address,
+    )
+}
+
+/// Generates multiple Ethereum addresses from a single mnemonic phrase without keeping their
+/// private keys.
+///
+/// # Arguments
+///
+/// * `mnemonic` - A BIP39 mnemonic phrase string
+/// * `start_index` - The starting index for the derivation path
+/// * `end_index` - The ending index for the derivation path (exclusive)
+///
+/// # Returns
+///
+/// * `Result<Vec<Address>>` - The addresses in index order on success
+#[instrument(level = "debug", skip(mnemonic))]
+pub fn generate_addresses(
+    mnemonic: &str,
+    start_index: u32,
+    end_index: u32,
+) -> Result<Vec<Address>> {
+    let deriver = Deriver::new(mnemonic, None)?;
+
+    (start_index..end_index)
+        .into_par_iter()
+        .map(|index| deriver.address(index))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::StreamExt;
+
+    const PHRASE: &str = "test test test test test test test test test test test junk";
+
+    #[test]
+    fn test_account_chunks_are_ordered() {
+        let chunks = stream_accounts(PHRASE, 0, 10, 4)
+            .unwrap()
+            .into_iter()
+            .collect::<Result<Vec<_>>>()
+            .unwrap();
+
+        let sizes: Vec<usize> = chunks.iter().map(Vec::len).collect();
+        assert_eq!(sizes, vec![4, 4, 2]);
+
+        let addresses: Vec<Address> = chunks.concat().iter().map(|s| s.address()).collect();
+        assert_eq!(addresses, generate_addresses(PHRASE, 0, 10).unwrap());
+        assert_eq!(
+            addresses[0].to_string(),
+            "0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_address_stream() {
+        let mut chunks = stream_addresses(PHRASE, 1, 3, 1).unwrap();
+
+        let first = chunks.next().await.unwrap().unwrap();
+        assert_eq!(
+            first[0].to_string(),
+            "0x70997970C51812dc3A010C7d01b50e0d17dc79C8"
+        );
+        assert_eq!(chunks.next().await.unwrap().unwrap().len(), 1);
+        assert!(chunks.next().await.is_none());
+    }
+
+    #[test]
+    fn test_zero_chunk_size() {
+        assert!(stream_addresses(PHRASE, 0, 1, 0).is_err());
+    }
+}
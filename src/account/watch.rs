@@ -0,0 +1,208 @@
+use super::{
+    derive::{derive_key, ACCOUNT_CHAIN_PATH, ACCOUNT_PATH},
+    SecretString,
+};
+use crate::network::http_provider;
+use alloy::{
+    primitives::{Address, U256},
+    providers::Provider,
+    signers::utils::public_key_to_address,
+    transports::http::reqwest::Url,
+};
+use coins_bip32::{
+    enc::{MainnetEncoder, XKeyEncoder},
+    prelude::VerifyingKey,
+    primitives::{Hint, XKeyInfo},
+    xkeys::{Parent, XPub},
+};
+use eyre::{eyre, Result};
+use futures::{stream, StreamExt, TryStreamExt};
+use rayon::prelude::*;
+use std::future::IntoFuture;
+use tracing::instrument;
+
+/// The maximum number of balance requests in flight at once.
+pub const BALANCE_CONCURRENCY: usize = 16;
+
+/// The depth of the account node (`m/44'/60'/0'`) in the HD tree.
+const ACCOUNT_DEPTH: u8 = 3;
+
+/// The depth of the account's external chain node (`m/44'/60'/0'/0`) in the HD tree.
+const CHAIN_DEPTH: u8 = 4;
+
+/// Exports the account-level extended public key of a mnemonic phrase.
+///
+/// The result can be handed to a machine that must never hold the seed, which can then compute
+/// the same addresses as [`generate_accounts`](super::generate_accounts) with a [`WatchOnlyWallet`].
+/// A wallet protected by a BIP39 passphrase must pass it, as for
+/// [`generate_accounts_with_passphrase`](super::generate_accounts_with_passphrase).
+///
+/// # Arguments
+///
+/// * `mnemonic` - A BIP39 mnemonic phrase string
+/// * `passphrase` - The BIP39 passphrase of the wallet, if any
+///
+/// # Returns
+///
+/// * `Result<String>` - The base58 encoded `xpub` of `m/44'/60'/0'` on success
+pub fn account_xpub(mnemonic: &str, passphrase: Option<&SecretString>) -> Result<String> {
+    let account = derive_key(mnemonic, passphrase, ACCOUNT_PATH)?.verify_key();
+
+    // the seed root defaults to a segwit hint, which would encode as `zpub`
+    let info = XKeyInfo {
+        hint: Hint::Legacy,
+        ..*AsRef::<XKeyInfo>::as_ref(&account)
+    };
+    let account = XPub::new(*AsRef::<VerifyingKey>::as_ref(&account), info);
+
+    Ok(MainnetEncoder::xpub_to_base58(&account)?)
+}
+
+/// A watch-only wallet deriving addresses from an extended public key.
+///
+/// Only non-hardened children are derived, so the addresses match the ones produced from the
+/// mnemonic without any private key being present.
+#[derive(Debug, Clone)]
+pub struct WatchOnlyWallet {
+    chain: XPub,
+}
+
+impl WatchOnlyWallet {
+    /// Creates a new `WatchOnlyWallet` instance from a base58 encoded extended public key.
+    ///
+    /// # Arguments
+    ///
+    /// * `xpub` - The `xpub` of either the account node (`m/44'/60'/0'`) or its external chain
+    ///   node (`m/44'/60'/0'/0`)
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Self>` - A new `WatchOnlyWallet` instance on success
+    pub fn from_xpub(xpub: &str) -> Result<Self> {
+        let key = MainnetEncoder::xpub_from_base58(xpub)?;
+        let info: &XKeyInfo = key.as_ref();
+
+        let chain = match info.depth {
+            ACCOUNT_DEPTH => key.derive_child(0)?,
+            CHAIN_DEPTH => key,
+            depth => {
+                return Err(eyre!(
+                    "expected an xpub at depth {ACCOUNT_DEPTH} or {CHAIN_DEPTH} ({ACCOUNT_PATH} or {ACCOUNT_CHAIN_PATH}), got depth {depth}"
+                ))
+            }
+        };
+
+        Ok(Self { chain })
+    }
+
+    /// Derives the address at the given index.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The index of the account on the external chain
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Address>` - The address on success
+    pub fn address(&self, index: u32) -> Result<Address> {
+        let child = self.chain.derive_child(index)?;
+        let key: &VerifyingKey = child.as_ref();
+
+        Ok(public_key_to_address(key))
+    }
+
+    /// Derives the addresses of a range of indices.
+    ///
+    /// # Arguments
+    ///
+    /// * `start_index` - The starting index for the derivation path
+    /// * `end_index` - The ending index for the derivation path (exclusive)
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Vec<Address>>` - The addresses in index order on success
+    pub fn addresses(&self, start_index: u32, end_index: u32) -> Result<Vec<Address>> {
+        (start_index..end_index)
+            .into_par_iter()
+            .map(|index| self.address(index))
+            .collect()
+    }
+
+    /// Fetches the native balances of a range of indices.
+    ///
+    /// At most [`BALANCE_CONCURRENCY`] requests are in flight at once, so large ranges do not
+    /// flood the RPC endpoint.
+    ///
+    /// # Arguments
+    ///
+    /// * `rpc_http` - The HTTP URL of the Ethereum RPC endpoint.
+    /// * `start_index` - The starting index for the derivation path
+    /// * `end_index` - The ending index for the derivation path (exclusive)
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Vec<(Address, U256)>>` - The addresses and their balances in index order on success
+    #[instrument(level = "debug", skip(self, rpc_http))]
+    pub async fn balances(
+        &self,
+        rpc_http: Url,
+        start_index: u32,
+        end_index: u32,
+    ) -> Result<Vec<(Address, U256)>> {
+        let provider = http_provider(rpc_http);
+        let addresses = self.addresses(start_index, end_index)?;
+
+        let balances: Vec<U256> = stream::iter(&addresses)
+            .map(|address| provider.get_balance(*address).into_future())
+            .buffered(BALANCE_CONCURRENCY)
+            .try_collect()
+            .await?;
+
+        Ok(addresses.into_iter().zip(balances).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::account::{generate_accounts_with_passphrase, generate_addresses};
+    use alloy::signers::local::coins_bip39::{English, Mnemonic};
+
+    const PHRASE: &str = "test test test test test test test test test test test junk";
+
+    #[test]
+    fn test_watch_only_addresses_match_mnemonic() {
+        let xpub = account_xpub(PHRASE, None).unwrap();
+        assert!(xpub.starts_with("xpub"));
+
+        let wallet = WatchOnlyWallet::from_xpub(&xpub).unwrap();
+        assert_eq!(
+            wallet.addresses(0, 20).unwrap(),
+            generate_addresses(PHRASE, 0, 20).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_watch_only_addresses_match_passphrase() {
+        let passphrase = SecretString::from("TREZOR");
+        let xpub = account_xpub(PHRASE, Some(&passphrase)).unwrap();
+        assert_ne!(xpub, account_xpub(PHRASE, None).unwrap());
+
+        let addresses: Vec<_> = generate_accounts_with_passphrase(PHRASE, &passphrase, 0, 5)
+            .unwrap()
+            .iter()
+            .map(|account| account.address())
+            .collect();
+        let wallet = WatchOnlyWallet::from_xpub(&xpub).unwrap();
+        assert_eq!(wallet.addresses(0, 5).unwrap(), addresses);
+    }
+
+    #[test]
+    fn test_watch_only_rejects_wrong_depth() {
+        let mnemonic = Mnemonic::<English>::new_from_phrase(PHRASE).unwrap();
+        let master = mnemonic.master_key(None).unwrap().verify_key();
+        let xpub = MainnetEncoder::xpub_to_base58(&master).unwrap();
+
+        assert!(WatchOnlyWallet::from_xpub(&xpub).is_err());
+    }
+}
@@ -0,0 +1,97 @@
+use alloy::primitives::{Address, U256};
+use eyre::{eyre, Result, WrapErr};
+use serde_json::Value;
+use std::{collections::HashMap, fs, path::Path};
+
+/// Parses an allowlist of addresses and their allotted amounts.
+///
+/// Two formats are accepted:
+///
+/// * a JSON object, e.g. `{ "0xf39F…2266": "5", "0x7099…79C8": 10 }`;
+/// * CSV lines of `address,amount`, with an optional `address,amount` header.
+///
+/// # Arguments
+///
+/// * `content` - The content of the allowlist.
+///
+/// # Returns
+///
+/// * `Result<Vec<(Address, U256)>>` - The entries of the allowlist on success.
+pub fn parse_allowlist(content: &str) -> Result<Vec<(Address, U256)>> {
+    if content.trim_start().starts_with('{') {
+        let entries: HashMap<Address, Value> =
+            serde_json::from_str(content).wrap_err("allowlist is not valid JSON")?;
+
+        return entries
+            .into_iter()
+            .map(|(address, amount)| {
+                let amount = match amount {
+                    Value::String(amount) => amount.parse()?,
+                    Value::Number(amount) => amount.to_string().parse()?,
+                    amount => return Err(eyre!("invalid amount `{amount}` for {address}")),
+                };
+                Ok((address, amount))
+            })
+            .collect();
+    }
+
+    content
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .enumerate()
+        .filter(|(number, line)| !(*number == 0 && line.starts_with("address")))
+        .map(|(number, line)| {
+            let (address, amount) = line
+                .split_once(',')
+                .ok_or_else(|| eyre!("line {}: expected `address,amount`", number + 1))?;
+            let address = address
+                .trim()
+                .parse()
+                .wrap_err_with(|| format!("line {}: invalid address `{address}`", number + 1))?;
+            let amount = amount
+                .trim()
+                .parse()
+                .wrap_err_with(|| format!("line {}: invalid amount `{amount}`", number + 1))?;
+            Ok((address, amount))
+        })
+        .collect()
+}
+
+/// Loads an allowlist from a JSON or CSV file, see [`parse_allowlist`].
+///
+/// # Arguments
+///
+/// * `path` - The path of the allowlist.
+///
+/// # Returns
+///
+/// * `Result<Vec<(Address, U256)>>` - The entries of the allowlist on success.
+pub fn load_allowlist<P: AsRef<Path>>(path: P) -> Result<Vec<(Address, U256)>> {
+    let path = path.as_ref();
+    let content = fs::read_to_string(path)
+        .wrap_err_with(|| format!("failed to read allowlist `{}`", path.display()))?;
+
+    parse_allowlist(&content).wrap_err_with(|| format!("invalid allowlist `{}`", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_allowlist() {
+        let (alice, bob) = (Address::repeat_byte(1), Address::repeat_byte(2));
+
+        let mut json = parse_allowlist(&format!(r#"{{"{alice}": "5", "{bob}": 10}}"#)).unwrap();
+        json.sort();
+        let csv = parse_allowlist(&format!("address,amount\n{alice},5\n\n{bob}, 10\n")).unwrap();
+
+        assert_eq!(json, [(alice, U256::from(5)), (bob, U256::from(10))]);
+        assert_eq!(csv, json);
+
+        assert!(parse_allowlist(&format!("{alice}")).is_err());
+        assert!(parse_allowlist(&format!("{alice},lots")).is_err());
+        assert!(parse_allowlist(&format!(r#"{{"{alice}": true}}"#)).is_err());
+    }
+}
@@ -0,0 +1,222 @@
+use crate::{
+    executor::CallRequest,
+    mint::{MintCall, MintEncoder},
+};
+use alloy::{
+    dyn_abi::DynSolValue,
+    primitives::{keccak256, Address, B256, U256},
+    sol_types::SolValue,
+};
+use eyre::{eyre, Result};
+use std::collections::HashMap;
+
+/// Computes the leaf of an allowlist entry.
+///
+/// The leaf is `keccak256(bytes.concat(keccak256(abi.encode(address, amount))))`, as in
+/// OpenZeppelin's `StandardMerkleTree`.
+pub fn allowlist_leaf(address: Address, amount: U256) -> B256 {
+    keccak256(keccak256((address, amount).abi_encode_params()))
+}
+
+/// Hashes a pair of nodes in sorted order, as OpenZeppelin's `MerkleProof` does.
+fn hash_pair(a: B256, b: B256) -> B256 {
+    let (a, b) = if a <= b { (a, b) } else { (b, a) };
+    keccak256([a.as_slice(), b.as_slice()].concat())
+}
+
+/// Verifies a merkle proof the way OpenZeppelin's `MerkleProof.verify` does.
+///
+/// # Arguments
+///
+/// * `root` - The merkle root.
+/// * `leaf` - The leaf, see [`allowlist_leaf`].
+/// * `proof` - The sibling hashes from the leaf up to the root.
+///
+/// # Returns
+///
+/// * `bool` - `true` if the proof is valid.
+pub fn verify_proof(root: B256, leaf: B256, proof: &[B256]) -> bool {
+    proof
+        .iter()
+        .fold(leaf, |node, sibling| hash_pair(node, *sibling))
+        == root
+}
+
+/// A merkle tree of an allowlist, compatible with OpenZeppelin's `MerkleProof`.
+///
+/// The tree is laid out like `StandardMerkleTree` of `@openzeppelin/merkle-tree` with the
+/// `["address", "uint256"]` encoding, so it has the same root for the same entries.
+#[derive(Debug, Clone)]
+pub struct AllowlistTree {
+    nodes: Vec<B256>,
+    entries: HashMap<Address, (U256, usize)>,
+}
+
+impl AllowlistTree {
+    /// Builds the tree of an allowlist.
+    ///
+    /// # Arguments
+    ///
+    /// * `entries` - The addresses and their allotted amounts.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Self>` - The tree, or an error if the allowlist is empty or has duplicates.
+    pub fn new(entries: &[(Address, U256)]) -> Result<Self> {
+        if entries.is_empty() {
+            return Err(eyre!("the allowlist is empty"));
+        }
+
+        let mut leaves: Vec<(B256, Address, U256)> = entries
+            .iter()
+            .map(|&(address, amount)| (allowlist_leaf(address, amount), address, amount))
+            .collect();
+        leaves.sort_by_key(|(leaf, ..)| *leaf);
+
+        // leaves fill the end of the array in reverse order, each node `i` has its children
+        // at `2i + 1` and `2i + 2`
+        let len = 2 * leaves.len() - 1;
+        let mut nodes = vec![B256::ZERO; len];
+        let mut indexes = HashMap::with_capacity(leaves.len());
+        for (i, (leaf, address, amount)) in leaves.into_iter().enumerate() {
+            nodes[len - 1 - i] = leaf;
+            if indexes.insert(address, (amount, len - 1 - i)).is_some() {
+                return Err(eyre!("{address} appears twice in the allowlist"));
+            }
+        }
+        for i in (0..len - indexes.len()).rev() {
+            nodes[i] = hash_pair(nodes[2 * i + 1], nodes[2 * i + 2]);
+        }
+
+        Ok(Self {
+            nodes,
+            entries: indexes,
+        })
+    }
+
+    /// Returns the merkle root, to be set in the gated contract.
+    pub fn root(&self) -> B256 {
+        self.nodes[0]
+    }
+
+    /// Returns the amount allotted to an address, if it is in the allowlist.
+    pub fn amount(&self, address: Address) -> Option<U256> {
+        self.entries.get(&address).map(|(amount, _)| *amount)
+    }
+
+    /// Returns the merkle proof of an address, if it is in the allowlist.
+    pub fn proof(&self, address: Address) -> Option<Vec<B256>> {
+        let (_, mut index) = *self.entries.get(&address)?;
+
+        let mut proof = Vec::new();
+        while index > 0 {
+            let sibling = if index % 2 == 0 { index - 1 } else { index + 1 };
+            proof.push(self.nodes[sibling]);
+            index = (index - 1) / 2;
+        }
+
+        Some(proof)
+    }
+}
+
+/// A mint call of an allowlist-gated function taking `(uint256 amount, bytes32[] proof)`.
+///
+/// # Fields
+///
+/// * `call` - The called function, value and contract; its `args` are ignored.
+/// * `tree` - The allowlist the amounts and proofs come from.
+#[derive(Debug, Clone)]
+pub struct AllowlistCall {
+    pub call: MintCall,
+    pub tree: AllowlistTree,
+}
+
+impl AllowlistCall {
+    /// Creates a new `AllowlistCall` instance.
+    pub fn new(call: MintCall, tree: AllowlistTree) -> Self {
+        Self { call, tree }
+    }
+}
+
+impl MintEncoder for AllowlistCall {
+    fn encode_for(&self, _index: usize, signer: Address) -> Result<CallRequest> {
+        let (amount, proof) = self
+            .tree
+            .amount(signer)
+            .zip(self.tree.proof(signer))
+            .ok_or_else(|| eyre!("{signer} is not in the allowlist"))?;
+        let proof = proof
+            .into_iter()
+            .map(|node| DynSolValue::FixedBytes(node, 32))
+            .collect();
+
+        CallRequest::from_abi(
+            &self.call.abi,
+            self.call.contract_address,
+            &self.call.function_name,
+            &[amount.into(), DynSolValue::Array(proof)],
+            self.call.value,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy::primitives::b256;
+
+    #[test]
+    fn test_proofs_verify() {
+        for len in 1..=9u8 {
+            let entries: Vec<(Address, U256)> = (0..len)
+                .map(|i| (Address::repeat_byte(i + 1), U256::from(i)))
+                .collect();
+            let tree = AllowlistTree::new(&entries).unwrap();
+
+            for (address, amount) in &entries {
+                let proof = tree.proof(*address).unwrap();
+                let leaf = allowlist_leaf(*address, *amount);
+
+                assert!(verify_proof(tree.root(), leaf, &proof));
+                assert!(!verify_proof(
+                    tree.root(),
+                    allowlist_leaf(*address, amount + U256::from(1)),
+                    &proof
+                ));
+            }
+        }
+    }
+
+    #[test]
+    fn test_openzeppelin_root() {
+        // the example of the `@openzeppelin/merkle-tree` README
+        let tree = AllowlistTree::new(&[
+            (
+                Address::repeat_byte(0x11),
+                U256::from(5_000_000_000_000_000_000u128),
+            ),
+            (
+                Address::repeat_byte(0x22),
+                U256::from(2_500_000_000_000_000_000u128),
+            ),
+        ])
+        .unwrap();
+
+        assert_eq!(
+            tree.root(),
+            b256!("d4dee0beab2d53f2cc83e567171bd2820e49898130a22622b10ead383e90bd77")
+        );
+    }
+
+    #[test]
+    fn test_invalid_allowlists() {
+        let alice = (Address::repeat_byte(1), U256::from(1));
+
+        assert!(AllowlistTree::new(&[]).is_err());
+        assert!(AllowlistTree::new(&[alice, alice]).is_err());
+
+        let tree = AllowlistTree::new(&[alice]).unwrap();
+        assert_eq!(tree.root(), allowlist_leaf(alice.0, alice.1));
+        assert!(tree.proof(Address::ZERO).is_none());
+    }
+}
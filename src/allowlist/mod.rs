@@ -0,0 +1,8 @@
+mod list;
+pub use list::{load_allowlist, parse_allowlist};
+
+mod merkle;
+pub use merkle::{allowlist_leaf, verify_proof, AllowlistCall, AllowlistTree};
+
+mod voucher;
+pub use voucher::{sign_voucher, voucher_domain, MintVoucher, Voucher, VoucherCall, Vouchers};
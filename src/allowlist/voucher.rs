@@ -0,0 +1,208 @@
+use crate::{
+    executor::CallRequest,
+    mint::{MintCall, MintEncoder},
+};
+use alloy::{
+    dyn_abi::DynSolValue,
+    primitives::{Address, Bytes, U256},
+    signers::Signer,
+    sol,
+    sol_types::{Eip712Domain, SolStruct},
+};
+use eyre::{eyre, Result, WrapErr};
+use serde::Deserialize;
+use std::{borrow::Cow, collections::HashMap, fs, path::Path};
+
+sol! {
+    /// The EIP-712 voucher allowing `minter` to mint `amount` tokens.
+    #[derive(Debug, PartialEq, Eq)]
+    struct MintVoucher {
+        address minter;
+        uint256 amount;
+    }
+}
+
+/// Builds the EIP-712 domain of a voucher-gated contract.
+///
+/// # Arguments
+///
+/// * `name` - The name of the signing domain, e.g. `"AllowlistMint"`.
+/// * `version` - The version of the signing domain, e.g. `"1"`.
+/// * `chain_id` - The chain id of the network.
+/// * `verifying_contract` - The address of the gated contract.
+///
+/// # Returns
+///
+/// * `Eip712Domain` - The domain vouchers are signed for.
+pub fn voucher_domain(
+    name: &str,
+    version: &str,
+    chain_id: u64,
+    verifying_contract: Address,
+) -> Eip712Domain {
+    Eip712Domain::new(
+        Some(Cow::Owned(name.to_owned())),
+        Some(Cow::Owned(version.to_owned())),
+        Some(U256::from(chain_id)),
+        Some(verifying_contract),
+        None,
+    )
+}
+
+/// A signed voucher of a minter.
+///
+/// # Fields
+///
+/// * `amount` - The amount the minter may mint.
+/// * `signature` - The 65-byte signature of the [`MintVoucher`].
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct Voucher {
+    pub amount: U256,
+    pub signature: Bytes,
+}
+
+/// Signs the voucher of a minter.
+///
+/// # Arguments
+///
+/// * `issuer` - The signer trusted by the gated contract.
+/// * `domain` - The EIP-712 domain of the gated contract, see [`voucher_domain`].
+/// * `minter` - The address allowed to mint.
+/// * `amount` - The amount the minter may mint.
+///
+/// # Returns
+///
+/// * `Result<Voucher>` - The signed voucher on success.
+pub async fn sign_voucher<S: Signer + Sync>(
+    issuer: &S,
+    domain: &Eip712Domain,
+    minter: Address,
+    amount: U256,
+) -> Result<Voucher> {
+    let hash = MintVoucher { minter, amount }.eip712_signing_hash(domain);
+    let signature = issuer.sign_hash(&hash).await?;
+
+    Ok(Voucher {
+        amount,
+        signature: signature.as_bytes().into(),
+    })
+}
+
+/// Signed vouchers keyed by minter address.
+///
+/// Loaded from JSON such as `{ "0xf39F…2266": { "amount": "5", "signature": "0x…" } }`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Vouchers {
+    vouchers: HashMap<Address, Voucher>,
+}
+
+impl Vouchers {
+    /// Parses vouchers from JSON content.
+    pub fn from_json_str(content: &str) -> Result<Self> {
+        let vouchers = serde_json::from_str(content).wrap_err("vouchers are not valid")?;
+
+        Ok(Self { vouchers })
+    }
+
+    /// Loads vouchers from a JSON file.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the file.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Self>` - The vouchers on success.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref();
+        let content = fs::read_to_string(path)
+            .wrap_err_with(|| format!("failed to read vouchers `{}`", path.display()))?;
+
+        Self::from_json_str(&content)
+            .wrap_err_with(|| format!("invalid vouchers `{}`", path.display()))
+    }
+
+    /// Adds or replaces the voucher of a minter.
+    pub fn insert(&mut self, minter: Address, voucher: Voucher) {
+        self.vouchers.insert(minter, voucher);
+    }
+
+    /// Returns the voucher of a minter, if any.
+    pub fn get(&self, minter: Address) -> Option<&Voucher> {
+        self.vouchers.get(&minter)
+    }
+}
+
+/// A mint call of a voucher-gated function taking `(uint256 amount, bytes signature)`.
+///
+/// # Fields
+///
+/// * `call` - The called function, value and contract; its `args` are ignored.
+/// * `vouchers` - The vouchers of the signers.
+#[derive(Debug, Clone)]
+pub struct VoucherCall {
+    pub call: MintCall,
+    pub vouchers: Vouchers,
+}
+
+impl VoucherCall {
+    /// Creates a new `VoucherCall` instance.
+    pub fn new(call: MintCall, vouchers: Vouchers) -> Self {
+        Self { call, vouchers }
+    }
+}
+
+impl MintEncoder for VoucherCall {
+    fn encode_for(&self, _index: usize, signer: Address) -> Result<CallRequest> {
+        let voucher = self
+            .vouchers
+            .get(signer)
+            .ok_or_else(|| eyre!("{signer} has no voucher"))?;
+
+        CallRequest::from_abi(
+            &self.call.abi,
+            self.call.contract_address,
+            &self.call.function_name,
+            &[
+                voucher.amount.into(),
+                DynSolValue::Bytes(voucher.signature.to_vec()),
+            ],
+            self.call.value,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy::{primitives::PrimitiveSignature, signers::local::PrivateKeySigner};
+
+    #[tokio::test]
+    async fn test_sign_voucher() {
+        let issuer = PrivateKeySigner::random();
+        let domain = voucher_domain("AllowlistMint", "1", 31337, Address::repeat_byte(1));
+        let minter = Address::repeat_byte(2);
+
+        let voucher = sign_voucher(&issuer, &domain, minter, U256::from(5))
+            .await
+            .unwrap();
+        let content = format!(
+            r#"{{"{minter}": {{"amount": "5", "signature": "{}"}}}}"#,
+            voucher.signature
+        );
+        let vouchers = Vouchers::from_json_str(&content).unwrap();
+        assert_eq!(vouchers.get(minter), Some(&voucher));
+
+        // the signature recovers to the issuer
+        let hash = MintVoucher {
+            minter,
+            amount: U256::from(5),
+        }
+        .eip712_signing_hash(&domain);
+        let signature = PrimitiveSignature::try_from(voucher.signature.as_ref()).unwrap();
+        assert_eq!(
+            signature.recover_address_from_prehash(&hash).unwrap(),
+            issuer.address()
+        );
+    }
+}
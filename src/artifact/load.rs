@@ -0,0 +1,214 @@
+use alloy::{json_abi::JsonAbi, primitives::Bytes};
+use eyre::{eyre, Result, WrapErr};
+use serde_json::Value;
+use std::{fs, path::Path};
+
+/// The formats an artifact can be loaded from.
+///
+/// # Variants
+///
+/// * `Foundry` - A Foundry build output, e.g. `out/FreeMint.sol/FreeMint.json`.
+/// * `Hardhat` - A Hardhat build output, e.g. `artifacts/contracts/FreeMint.sol/FreeMint.json`.
+/// * `Abi` - A plain JSON ABI.
+/// * `HumanReadable` - A JSON array of human-readable signatures, e.g. `["function mint()"]`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArtifactFormat {
+    Foundry,
+    Hardhat,
+    Abi,
+    HumanReadable,
+}
+
+/// A contract ABI, with its bytecode when the source provides it.
+///
+/// # Fields
+///
+/// * `format` - The format the artifact was loaded from.
+/// * `abi` - The JSON ABI of the contract.
+/// * `bytecode` - The creation bytecode of the contract (optional).
+/// * `deployed_bytecode` - The runtime bytecode of the contract (optional).
+#[derive(Debug, Clone)]
+pub struct Artifact {
+    pub format: ArtifactFormat,
+    pub abi: JsonAbi,
+    pub bytecode: Option<Bytes>,
+    pub deployed_bytecode: Option<Bytes>,
+}
+
+impl Artifact {
+    /// Returns the creation bytecode, failing if the artifact has none.
+    pub fn bytecode(&self) -> Result<&Bytes> {
+        self.bytecode
+            .as_ref()
+            .ok_or_else(|| eyre!("the {:?} artifact has no bytecode", self.format))
+    }
+}
+
+/// Parses a JSON ABI from human-readable signatures.
+///
+/// # Arguments
+///
+/// * `signatures` - The signatures, e.g. `["function mint()", "function totalSupply() view returns (uint256)"]`.
+///
+/// # Returns
+///
+/// * `Result<JsonAbi>` - The JSON ABI on success.
+pub fn parse_signatures<'a, I>(signatures: I) -> Result<JsonAbi>
+where
+    I: IntoIterator<Item = &'a str>,
+{
+    JsonAbi::parse(signatures).wrap_err("invalid human-readable ABI")
+}
+
+/// Parses an artifact, detecting its format.
+///
+/// # Arguments
+///
+/// * `content` - The JSON content of the artifact.
+///
+/// # Returns
+///
+/// * `Result<Artifact>` - The artifact on success.
+pub fn parse_artifact(content: &str) -> Result<Artifact> {
+    let value: Value = serde_json::from_str(content).wrap_err("artifact is not valid JSON")?;
+
+    match value {
+        Value::Array(items) if items.iter().all(Value::is_string) => {
+            let signatures: Vec<&str> = items.iter().filter_map(Value::as_str).collect();
+
+            Ok(Artifact {
+                format: ArtifactFormat::HumanReadable,
+                abi: parse_signatures(signatures)?,
+                bytecode: None,
+                deployed_bytecode: None,
+            })
+        }
+        Value::Array(_) => Ok(Artifact {
+            format: ArtifactFormat::Abi,
+            abi: serde_json::from_value(value)?,
+            bytecode: None,
+            deployed_bytecode: None,
+        }),
+        Value::Object(mut object) => {
+            let abi = object
+                .remove("abi")
+                .ok_or_else(|| eyre!("artifact has no `abi` field"))?;
+            let abi = match abi {
+                // some tools store the ABI as an encoded string
+                Value::String(abi) => serde_json::from_str(&abi)?,
+                abi => serde_json::from_value(abi)?,
+            };
+
+            // Foundry nests the bytecode under `object`, Hardhat stores it as a string
+            let format = match object.get("bytecode") {
+                Some(Value::Object(_)) => ArtifactFormat::Foundry,
+                _ if object.contains_key("_format") => ArtifactFormat::Hardhat,
+                Some(Value::String(_)) => ArtifactFormat::Hardhat,
+                _ => ArtifactFormat::Foundry,
+            };
+
+            Ok(Artifact {
+                format,
+                abi,
+                bytecode: bytecode(object.get("bytecode"))?,
+                deployed_bytecode: bytecode(object.get("deployedBytecode"))?,
+            })
+        }
+        _ => Err(eyre!("artifact must be a JSON object or array")),
+    }
+}
+
+/// Extracts bytecode from either `"0x…"` or `{ "object": "0x…" }`.
+fn bytecode(value: Option<&Value>) -> Result<Option<Bytes>> {
+    let code = match value {
+        Some(Value::String(code)) => code,
+        Some(Value::Object(object)) => match object.get("object") {
+            Some(Value::String(code)) => code,
+            _ => return Ok(None),
+        },
+        _ => return Ok(None),
+    };
+
+    let code = code.trim_start_matches("0x");
+    if code.is_empty() {
+        return Ok(None);
+    }
+    if code.contains("__") {
+        return Err(eyre!("bytecode has unlinked library references"));
+    }
+
+    Ok(Some(code.parse().wrap_err("bytecode is not valid hex")?))
+}
+
+/// Loads an artifact from a file, detecting its format.
+///
+/// # Arguments
+///
+/// * `path` - The path of a Foundry or Hardhat artifact, a plain JSON ABI, or a JSON array of
+///   human-readable signatures.
+///
+/// # Returns
+///
+/// * `Result<Artifact>` - The artifact on success.
+pub fn load_artifact<P: AsRef<Path>>(path: P) -> Result<Artifact> {
+    let path = path.as_ref();
+    let content = fs::read_to_string(path)
+        .wrap_err_with(|| format!("failed to read artifact `{}`", path.display()))?;
+
+    parse_artifact(&content).wrap_err_with(|| format!("invalid artifact `{}`", path.display()))
+}
+
+/// Loads only the ABI of an artifact.
+///
+/// # Arguments
+///
+/// * `path` - The path of the artifact, in any format supported by [`load_artifact`].
+///
+/// # Returns
+///
+/// * `Result<JsonAbi>` - The JSON ABI on success.
+pub fn load_abi<P: AsRef<Path>>(path: P) -> Result<JsonAbi> {
+    Ok(load_artifact(path)?.abi)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ABI: &str = r#"[{"type":"function","name":"mint","inputs":[],"outputs":[],"stateMutability":"nonpayable"}]"#;
+
+    #[test]
+    fn test_parse_formats() {
+        let foundry = format!(
+            r#"{{"abi":{ABI},"bytecode":{{"object":"0x6080"}},"deployedBytecode":{{"object":"0x60"}}}}"#
+        );
+        let hardhat = format!(
+            r#"{{"_format":"hh-sol-artifact-1","abi":{ABI},"bytecode":"0x6080","deployedBytecode":"0x"}}"#
+        );
+
+        let artifact = parse_artifact(&foundry).unwrap();
+        assert_eq!(artifact.format, ArtifactFormat::Foundry);
+        assert_eq!(artifact.bytecode().unwrap().as_ref(), &[0x60, 0x80]);
+        assert!(artifact.deployed_bytecode.is_some());
+
+        let artifact = parse_artifact(&hardhat).unwrap();
+        assert_eq!(artifact.format, ArtifactFormat::Hardhat);
+        assert_eq!(artifact.bytecode().unwrap().as_ref(), &[0x60, 0x80]);
+        assert!(artifact.deployed_bytecode.is_none());
+
+        let artifact = parse_artifact(ABI).unwrap();
+        assert_eq!(artifact.format, ArtifactFormat::Abi);
+        assert!(artifact.bytecode().is_err());
+
+        let artifact = parse_artifact(r#"["function mint()"]"#).unwrap();
+        assert_eq!(artifact.format, ArtifactFormat::HumanReadable);
+        assert_eq!(artifact.abi, parse_artifact(ABI).unwrap().abi);
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert!(parse_artifact("{}").is_err());
+        assert!(parse_artifact(r#"["function mint("]"#).is_err());
+        assert!(parse_artifact(&format!(r#"{{"abi":{ABI},"bytecode":"0x__$ab$__"}}"#)).is_err());
+    }
+}
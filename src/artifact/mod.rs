@@ -0,0 +1,4 @@
+mod load;
+pub use load::{
+    load_abi, load_artifact, parse_artifact, parse_signatures, Artifact, ArtifactFormat,
+};
@@ -0,0 +1,57 @@
+use alloy::sol;
+
+sol! {
+    /// Typed bindings of `contracts/src/Distributor.sol`.
+    ///
+    /// The embedded creation bytecode is a hand-assembled equivalent of the contract, not solc
+    /// output: it has the same selector, refund and `EtherTransferFail` revert, but neither the
+    /// solc prologue nor the metadata. It is to be replaced by the `bytecode.object` of
+    /// `contracts/out/Distributor.sol/Distributor.json` after `forge build`, see
+    /// `contracts/README.md`; until then the integration test
+    /// `test_embedded_bytecode_matches_artifact` fails.
+    #[allow(missing_docs)]
+    #[sol(
+        rpc,
+        bytecode = "60ba80600b6000396000f3600436106100185760003560e01c6315b41d811461001d575b600080fd5b600435600401803580640100000000111561001857906020019060061b8101803610610018575b80821015610069576000808080856020013586355af115610083579060400190610044565b505047801561008157600080808084335af11561008a575b005b813561008c565b335b634498e0fd60e01b6000523060045273ffffffffffffffffffffffffffffffffffffffff1660245260446000fd"
+    )]
+    contract Distributor {
+        #[derive(Debug, PartialEq, Eq)]
+        struct Transaction {
+            address recipient;
+            uint256 amount;
+        }
+
+        #[derive(Debug, PartialEq, Eq)]
+        error EtherTransferFail(address sender, address receiver);
+
+        function distributeEther(Transaction[] calldata txns) external payable;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy::{
+        hex,
+        primitives::keccak256,
+        sol_types::{SolCall, SolError},
+    };
+
+    #[test]
+    fn test_bytecode_matches_abi() {
+        let selector = Distributor::distributeEtherCall::SELECTOR;
+        assert_eq!(
+            selector,
+            keccak256("distributeEther((address,uint256)[])")[..4]
+        );
+
+        // the runtime dispatches on the selector of `distributeEther` and reverts with
+        // `EtherTransferFail` when a transfer fails
+        let bytecode = hex::encode(&*Distributor::BYTECODE);
+        assert!(bytecode.contains(&format!("63{}", hex::encode(selector))));
+        assert!(bytecode.contains(&format!(
+            "63{}",
+            hex::encode(Distributor::EtherTransferFail::SELECTOR)
+        )));
+    }
+}
@@ -0,0 +1,18 @@
+use alloy::sol;
+
+sol! {
+    /// Typed bindings of `contracts/src/FreeMint.sol`.
+    ///
+    /// Only the interface is embedded, the token itself is deployed by its authors.
+    #[allow(missing_docs)]
+    #[sol(rpc)]
+    contract FreeMint {
+        event Transfer(address indexed from, address indexed to, uint256 value);
+
+        function MAX_SUPPLY() external view returns (uint256);
+        function MINT_AMOUNT() external view returns (uint256);
+        function totalSupply() external view returns (uint256);
+        function balanceOf(address account) external view returns (uint256);
+        function mint() external;
+    }
+}
@@ -0,0 +1,5 @@
+mod distributor;
+pub use distributor::Distributor;
+
+mod free_mint;
+pub use free_mint::FreeMint;
@@ -0,0 +1,1030 @@
+use crate::{
+    account::SecretString,
+    artifact::{load_abi, parse_signatures},
+    launch::{LaunchCondition, LaunchWatcher},
+    mint::{MintPrice, MintedScan, SupplyCheck},
+    network::{BaseFeeGate, FeeStrategy, NetworkProfile},
+    relay::{BundleRelay, RelayMethod, DEFAULT_RELAY_BLOCKS},
+};
+use alloy::{
+    json_abi::JsonAbi,
+    primitives::{
+        utils::{parse_units, ParseUnits},
+        Address, Bytes, U256,
+    },
+    transports::http::reqwest::Url,
+};
+use eyre::{eyre, Result, WrapErr};
+use serde::Deserialize;
+use std::{
+    fs,
+    net::SocketAddr,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+/// A declarative description of a whole minting campaign.
+///
+/// A campaign is loaded from a TOML file such as:
+///
+/// ```toml
+/// [network]
+/// profile = "sepolia"
+/// rpc_urls = ["https://rpc.sepolia.org"]
+///
+/// [accounts]
+/// mnemonic_env = "MNEMONIC"
+/// start_index = 100
+/// end_index = 200
+///
+/// [funding]
+/// distributor = "0x5FbDB2315678afecb367f032d93F642f64180aa3"
+/// sender_key_env = "FUNDER_KEY"
+/// amount = "0.001 ether"
+///
+/// [mint]
+/// contract = "0xe7f1725E7734CE288F8367e1Bb143E90bb3F0512"
+/// abi = "abi/FreeMint.json"
+/// concurrency = 8
+///
+/// [fees]
+/// strategy = "fixed"
+/// max_fee_per_gas = "30 gwei"
+/// max_priority_fee_per_gas = "1 gwei"
+/// ```
+///
+/// Relative paths are resolved against the directory of the campaign file.
+///
+/// # Fields
+///
+/// * `network` - The network to run against.
+/// * `accounts` - Where the minting accounts come from.
+/// * `funding` - How the minting accounts are funded (optional, skipped when absent).
+/// * `mint` - The mint call.
+/// * `fees` - The fee strategy and limits.
+/// * `launch` - The condition to wait for before minting (optional, mints right away when
+///   absent).
+/// * `relay` - The bundle relay the mints are submitted through (optional, the public mempool
+///   of the RPC endpoint is used when absent).
+/// * `metrics` - The Prometheus endpoint served while the campaign runs (optional, requires
+///   the `metrics` feature).
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CampaignConfig {
+    pub network: NetworkConfig,
+    pub accounts: AccountsConfig,
+    pub funding: Option<FundingConfig>,
+    pub mint: MintConfig,
+    #[serde(default)]
+    pub fees: FeesConfig,
+    pub launch: Option<LaunchConfig>,
+    pub relay: Option<RelayConfig>,
+    pub metrics: Option<MetricsConfig>,
+    #[serde(skip)]
+    base_dir: PathBuf,
+}
+
+/// The `[network]` section of a campaign.
+///
+/// # Fields
+///
+/// * `profile` - The name of a built-in network profile (optional).
+/// * `chain_id` - The expected chain id, required without `profile` (optional).
+/// * `currency_symbol` - The symbol of the native currency (optional).
+/// * `explorer_url` - A block explorer URL template containing `{tx_hash}` (optional).
+/// * `rpc_urls` - The RPC endpoints, in order of preference.
+/// * `confirmations` - The blocks a transaction must be buried under before it is final;
+///   transactions dropped by a reorg meanwhile are sent again (optional, the first inclusion
+///   is final by default).
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct NetworkConfig {
+    pub profile: Option<String>,
+    pub chain_id: Option<u64>,
+    pub currency_symbol: Option<String>,
+    pub explorer_url: Option<String>,
+    pub rpc_urls: Vec<String>,
+    pub confirmations: Option<u64>,
+}
+
+/// The `[accounts]` section of a campaign.
+///
+/// Exactly one of `mnemonic_env` and `mnemonic_file` must be set; the mnemonic itself never
+/// appears in the campaign file.
+///
+/// # Fields
+///
+/// * `mnemonic_env` - The environment variable holding the mnemonic (optional).
+/// * `mnemonic_file` - The file holding the mnemonic (optional).
+/// * `start_index` - The starting index for the derivation path.
+/// * `end_index` - The ending index for the derivation path (exclusive).
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct AccountsConfig {
+    pub mnemonic_env: Option<String>,
+    pub mnemonic_file: Option<PathBuf>,
+    pub start_index: u32,
+    pub end_index: u32,
+}
+
+/// How much each account is funded with.
+///
+/// # Variants
+///
+/// * `Fixed` - Every account receives `amount`.
+/// * `TopUp` - Every account receives what it lacks to hold `amount`.
+/// * `MintCost` - Every account receives what it lacks to afford its mint, i.e. the mint
+///   price plus its estimated gas at the maximum fee per gas, plus `amount` as a margin;
+///   mints that cannot be estimated, e.g. before the launch, are assumed to use
+///   `mint.gas_limit`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FundingStrategy {
+    #[default]
+    Fixed,
+    TopUp,
+    MintCost,
+}
+
+/// The `[funding]` section of a campaign.
+///
+/// Exactly one of `sender_key_env` and `sender_key_file` must be set.
+///
+/// # Fields
+///
+/// * `distributor` - The address of the `Distributor` contract (optional, defaults to the
+///   deterministic deployment, which is deployed by the funder if missing).
+/// * `sender_key_env` - The environment variable holding the funder private key (optional).
+/// * `sender_key_file` - The file holding the funder private key (optional).
+/// * `amount` - The funding amount per account, e.g. `"0.001 ether"`; optional with the
+///   `mint_cost` strategy, where it is a margin.
+/// * `strategy` - The funding strategy, `fixed` by default.
+/// * `batch_size` - The maximum number of accounts funded per transaction.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct FundingConfig {
+    pub distributor: Option<Address>,
+    pub sender_key_env: Option<String>,
+    pub sender_key_file: Option<PathBuf>,
+    pub amount: Option<String>,
+    #[serde(default)]
+    pub strategy: FundingStrategy,
+    #[serde(default = "default_batch_size")]
+    pub batch_size: usize,
+}
+
+/// The `[mint]` section of a campaign.
+///
+/// # Fields
+///
+/// * `contract` - The address of the mint contract.
+/// * `abi` - The path of the contract ABI, in any format supported by
+///   [`load_artifact`](crate::artifact::load_artifact) (optional).
+/// * `signatures` - Human-readable signatures, e.g. `["function mint()"]`, used instead of
+///   `abi` for contracts without a published ABI.
+/// * `calldata` - Raw hex calldata, e.g. `"0x1249c58b"`, sent as is instead of calling a
+///   function of `abi` or `signatures` (optional).
+/// * `function` - The name of the function to execute, `mint` by default.
+/// * `args` - The arguments of the function, parsed according to the ABI; `{signer}` and
+///   `{index}` are replaced by the address and the derivation index of each account.
+/// * `args_file` - A JSON file of arguments keyed by account address, used instead of `args`,
+///   see [`SignerArgs`](crate::mint::SignerArgs) (optional).
+/// * `value` - The amount of Ether to send with each transaction, e.g. `"0.01 ether"` (optional).
+/// * `price_function` - A `uint256` view function of the contract returning the value to
+///   send, e.g. `mintPrice`, used instead of `value` (optional).
+/// * `gas_limit` - The gas assumed for a mint whose gas cannot be estimated, e.g. before the
+///   launch (optional).
+/// * `supply` - How to read the supply of a capped contract, enabling supply-aware minting;
+///   an empty `[mint.supply]` table uses the `FreeMint` view names (optional).
+/// * `gas_budget` - The most all mints together may spend on gas, e.g. `"0.05 ether"`;
+///   mints that would exceed it are skipped (optional).
+/// * `skip_minted` - How to scan the `Transfer` logs of the contract for accounts that already
+///   minted, which are then neither funded nor used; an empty `[mint.skip_minted]` table scans
+///   from the genesis block (optional).
+/// * `concurrency` - The maximum number of mint transactions in flight at once.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct MintConfig {
+    pub contract: Address,
+    pub abi: Option<PathBuf>,
+    #[serde(default)]
+    pub signatures: Vec<String>,
+    pub calldata: Option<String>,
+    #[serde(default = "default_function")]
+    pub function: String,
+    #[serde(default)]
+    pub args: Vec<String>,
+    pub args_file: Option<PathBuf>,
+    pub value: Option<String>,
+    pub price_function: Option<String>,
+    pub gas_limit: Option<u64>,
+    pub supply: Option<SupplyCheck>,
+    pub gas_budget: Option<String>,
+    pub skip_minted: Option<MintedScan>,
+    #[serde(default = "default_concurrency")]
+    pub concurrency: usize,
+}
+
+/// The kind of fee strategy of a campaign.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FeeKind {
+    Eip1559,
+    Legacy,
+    Fixed,
+}
+
+/// The `[fees]` section of a campaign.
+///
+/// # Fields
+///
+/// * `strategy` - Overrides the fee strategy of the network profile (optional).
+/// * `max_fee_per_gas` - The fee cap of the `fixed` strategy, e.g. `"30 gwei"` (optional).
+/// * `max_priority_fee_per_gas` - The priority fee of the `fixed` strategy (optional).
+/// * `max_base_fee` - Holds every transaction back while the base fee is above this amount,
+///   e.g. `"20 gwei"` (optional).
+/// * `base_fee_timeout_secs` - How long a transaction waits for the base fee to drop before
+///   giving up (optional, waits forever by default).
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct FeesConfig {
+    pub strategy: Option<FeeKind>,
+    pub max_fee_per_gas: Option<String>,
+    pub max_priority_fee_per_gas: Option<String>,
+    pub max_base_fee: Option<String>,
+    pub base_fee_timeout_secs: Option<u64>,
+}
+
+/// The kind of launch condition of a campaign, see [`LaunchCondition`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LaunchKind {
+    Code,
+    View,
+    Event,
+    Block,
+    Timestamp,
+}
+
+/// The `[launch]` section of a campaign.
+///
+/// The accounts are funded first, then the mints are sent as soon as the condition is met.
+///
+/// # Fields
+///
+/// * `condition` - What marks the launch.
+/// * `contract` - The contract of the `code`, `view` and `event` conditions (optional,
+///   defaults to `mint.contract`).
+/// * `function` - The `bool` view function of the `view` condition, e.g. `mintEnabled`
+///   (optional).
+/// * `event` - The event signature of the `event` condition, e.g. `SaleStarted(uint256)`
+///   (optional).
+/// * `block` - The block number of the `block` condition (optional).
+/// * `timestamp` - The unix timestamp of the `timestamp` condition (optional).
+/// * `poll_interval_ms` - The interval between two polls of the latest block, in
+///   milliseconds.
+/// * `timeout_secs` - How long to wait for the launch before giving up (optional).
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct LaunchConfig {
+    pub condition: LaunchKind,
+    pub contract: Option<Address>,
+    pub function: Option<String>,
+    pub event: Option<String>,
+    pub block: Option<u64>,
+    pub timestamp: Option<u64>,
+    #[serde(default = "default_poll_interval_ms")]
+    pub poll_interval_ms: u64,
+    pub timeout_secs: Option<u64>,
+}
+
+/// The `[relay]` section of a campaign.
+///
+/// Exactly one of `auth_key_env` and `auth_key_file` must be set. Only the mints go through
+/// the relay, funding transactions are sent publicly.
+///
+/// # Fields
+///
+/// * `url` - The URL of the relay, e.g. `https://relay.flashbots.net`.
+/// * `auth_key_env` - The environment variable holding the key signing relay requests
+///   (optional).
+/// * `auth_key_file` - The file holding the key signing relay requests (optional).
+/// * `method` - `bundle` (`eth_sendBundle`, the default) or `private_transaction`
+///   (`eth_sendPrivateTransaction`).
+/// * `max_blocks` - How many blocks a mint is retried for before giving up.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RelayConfig {
+    pub url: String,
+    pub auth_key_env: Option<String>,
+    pub auth_key_file: Option<PathBuf>,
+    #[serde(default)]
+    pub method: RelayMethod,
+    #[serde(default = "default_relay_blocks")]
+    pub max_blocks: u64,
+}
+
+/// The `[metrics]` section of a campaign.
+///
+/// # Fields
+///
+/// * `listen` - The address serving `/metrics`, e.g. `127.0.0.1:9898`.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct MetricsConfig {
+    pub listen: SocketAddr,
+}
+
+fn default_relay_blocks() -> u64 {
+    DEFAULT_RELAY_BLOCKS
+}
+
+fn default_poll_interval_ms() -> u64 {
+    1_000
+}
+
+fn default_batch_size() -> usize {
+    200
+}
+
+fn default_function() -> String {
+    "mint".to_owned()
+}
+
+fn default_concurrency() -> usize {
+    1
+}
+
+/// Parses an amount with an optional unit, e.g. `"0.001 ether"`, `"30 gwei"` or `"1000"` (wei).
+///
+/// # Arguments
+///
+/// * `amount` - The amount to parse.
+///
+/// # Returns
+///
+/// * `Result<U256>` - The amount in wei on success, or an error if it is negative.
+pub fn parse_amount(amount: &str) -> Result<U256> {
+    let mut parts = amount.split_whitespace();
+    let (value, unit) = match (parts.next(), parts.next(), parts.next()) {
+        (Some(value), None, None) => (value, "wei"),
+        (Some(value), Some(unit), None) => (value, unit),
+        _ => return Err(eyre!("invalid amount `{amount}`")),
+    };
+
+    let parsed = parse_units(value, unit).wrap_err_with(|| format!("invalid amount `{amount}`"))?;
+
+    match parsed {
+        ParseUnits::U256(amount) => Ok(amount),
+        ParseUnits::I256(value) if !value.is_negative() => Ok(value.into_raw()),
+        ParseUnits::I256(_) => Err(eyre!("invalid amount `{amount}`: it must not be negative")),
+    }
+}
+
+/// Reads a secret from exactly one of an environment variable and a file.
+fn load_secret(
+    what: &str,
+    env: Option<&String>,
+    file: Option<&PathBuf>,
+    base_dir: &Path,
+) -> Result<SecretString> {
+    match (env, file) {
+        (Some(name), None) => SecretString::from_env(name),
+        (None, Some(path)) => SecretString::from_file(base_dir.join(path)),
+        _ => Err(eyre!(
+            "exactly one of `{what}_env` and `{what}_file` must be set"
+        )),
+    }
+}
+
+impl CampaignConfig {
+    /// Loads and validates a campaign from a TOML file.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the campaign file.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Self>` - The validated campaign on success.
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref();
+        let content = fs::read_to_string(path)
+            .wrap_err_with(|| format!("failed to read campaign `{}`", path.display()))?;
+
+        let mut config = Self::from_toml_str(&content)?;
+        config.base_dir = path.parent().map(Path::to_path_buf).unwrap_or_default();
+
+        Ok(config)
+    }
+
+    /// Parses and validates a campaign from a TOML string.
+    ///
+    /// Relative paths are resolved against the current directory.
+    ///
+    /// # Arguments
+    ///
+    /// * `content` - The TOML content of the campaign.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Self>` - The validated campaign on success.
+    pub fn from_toml_str(content: &str) -> Result<Self> {
+        let config: Self = toml::from_str(content).wrap_err("invalid campaign file")?;
+        config.validate()?;
+
+        Ok(config)
+    }
+
+    /// Checks the campaign for inconsistencies that can be detected without any network access.
+    pub fn validate(&self) -> Result<()> {
+        self.rpc_urls()?;
+        self.network_profile()?;
+        self.fee_strategy()?;
+        self.base_fee_gate()?;
+
+        let accounts = &self.accounts;
+        if accounts.start_index >= accounts.end_index {
+            return Err(eyre!(
+                "`accounts.start_index` ({}) must be lower than `accounts.end_index` ({})",
+                accounts.start_index,
+                accounts.end_index
+            ));
+        }
+        if accounts.mnemonic_env.is_some() == accounts.mnemonic_file.is_some() {
+            return Err(eyre!(
+                "exactly one of `accounts.mnemonic_env` and `accounts.mnemonic_file` must be set"
+            ));
+        }
+
+        if let Some(funding) = &self.funding {
+            match &funding.amount {
+                Some(amount) => {
+                    parse_amount(amount)?;
+                }
+                None if funding.strategy != FundingStrategy::MintCost => {
+                    return Err(eyre!("`funding.amount` must be set"));
+                }
+                None => {}
+            }
+            if funding.batch_size == 0 {
+                return Err(eyre!("`funding.batch_size` must be greater than zero"));
+            }
+            if funding.sender_key_env.is_some() == funding.sender_key_file.is_some() {
+                return Err(eyre!(
+                    "exactly one of `funding.sender_key_env` and `funding.sender_key_file` must be set"
+                ));
+            }
+        }
+
+        let modes = [
+            self.mint.abi.is_some(),
+            !self.mint.signatures.is_empty(),
+            self.mint.calldata.is_some(),
+        ];
+        if modes.into_iter().filter(|&set| set).count() != 1 {
+            return Err(eyre!(
+                "exactly one of `mint.abi`, `mint.signatures` and `mint.calldata` must be set"
+            ));
+        }
+        if self.mint.calldata.is_some()
+            && (!self.mint.args.is_empty() || self.mint.args_file.is_some())
+        {
+            return Err(eyre!(
+                "`mint.args` cannot be used with `mint.calldata`, encode them in the calldata"
+            ));
+        }
+        if self.mint.args_file.is_some() && !self.mint.args.is_empty() {
+            return Err(eyre!(
+                "at most one of `mint.args` and `mint.args_file` can be set"
+            ));
+        }
+        self.mint_calldata()?;
+        if let Some(value) = &self.mint.value {
+            parse_amount(value)?;
+        }
+        if let Some(gas_budget) = &self.mint.gas_budget {
+            parse_amount(gas_budget)?;
+        }
+        if self.mint.value.is_some() && self.mint.price_function.is_some() {
+            return Err(eyre!(
+                "at most one of `mint.value` and `mint.price_function` can be set"
+            ));
+        }
+        if self.mint.concurrency == 0 {
+            return Err(eyre!("`mint.concurrency` must be greater than zero"));
+        }
+        self.launch_watcher()?;
+        if self.network.confirmations == Some(0) {
+            return Err(eyre!("`network.confirmations` must be greater than zero"));
+        }
+
+        if let Some(relay) = &self.relay {
+            relay
+                .url
+                .parse::<Url>()
+                .wrap_err_with(|| format!("invalid relay URL `{}`", relay.url))?;
+            if relay.auth_key_env.is_some() == relay.auth_key_file.is_some() {
+                return Err(eyre!(
+                    "exactly one of `relay.auth_key_env` and `relay.auth_key_file` must be set"
+                ));
+            }
+            if relay.max_blocks == 0 {
+                return Err(eyre!("`relay.max_blocks` must be greater than zero"));
+            }
+        }
+
+        if self.metrics.is_some() && !cfg!(feature = "metrics") {
+            return Err(eyre!(
+                "`[metrics]` requires stormint to be built with the `metrics` feature"
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// The RPC endpoints, in order of preference.
+    pub fn rpc_urls(&self) -> Result<Vec<Url>> {
+        if self.network.rpc_urls.is_empty() {
+            return Err(eyre!("`network.rpc_urls` must not be empty"));
+        }
+
+        self.network
+            .rpc_urls
+            .iter()
+            .map(|url| {
+                url.parse()
+                    .wrap_err_with(|| format!("invalid RPC URL `{url}`"))
+            })
+            .collect()
+    }
+
+    /// The network profile, built from the named profile and the overrides of `[network]`.
+    pub fn network_profile(&self) -> Result<NetworkProfile> {
+        let network = &self.network;
+
+        let mut profile = match (&network.profile, network.chain_id) {
+            (Some(name), _) => NetworkProfile::from_name(name)
+                .ok_or_else(|| eyre!("unknown network profile `{name}`"))?,
+            (None, Some(chain_id)) => NetworkProfile::new("custom", chain_id, "ETH"),
+            (None, None) => {
+                return Err(eyre!(
+                    "one of `network.profile` and `network.chain_id` must be set"
+                ))
+            }
+        };
+
+        if let Some(chain_id) = network.chain_id {
+            profile.chain_id = chain_id;
+        }
+        if let Some(symbol) = &network.currency_symbol {
+            profile.currency_symbol = symbol.clone();
+        }
+        if let Some(explorer_url) = &network.explorer_url {
+            profile.explorer_url = Some(explorer_url.clone());
+        }
+
+        Ok(profile)
+    }
+
+    /// The fee strategy override of `[fees]`, if any.
+    pub fn fee_strategy(&self) -> Result<Option<FeeStrategy>> {
+        let fees = &self.fees;
+
+        let strategy = match fees.strategy {
+            None => None,
+            Some(FeeKind::Eip1559) => Some(FeeStrategy::Eip1559),
+            Some(FeeKind::Legacy) => Some(FeeStrategy::Legacy),
+            Some(FeeKind::Fixed) => {
+                let (Some(max_fee), Some(max_priority_fee)) =
+                    (&fees.max_fee_per_gas, &fees.max_priority_fee_per_gas)
+                else {
+                    return Err(eyre!(
+                        "the `fixed` fee strategy requires `fees.max_fee_per_gas` and `fees.max_priority_fee_per_gas`"
+                    ));
+                };
+
+                Some(FeeStrategy::Fixed {
+                    max_fee_per_gas: parse_amount(max_fee)?.try_into()?,
+                    max_priority_fee_per_gas: parse_amount(max_priority_fee)?.try_into()?,
+                })
+            }
+        };
+
+        Ok(strategy)
+    }
+
+    /// The base fee gate of the `[fees]` section, if any.
+    pub fn base_fee_gate(&self) -> Result<Option<BaseFeeGate>> {
+        let fees = &self.fees;
+
+        let Some(max_base_fee) = &fees.max_base_fee else {
+            if fees.base_fee_timeout_secs.is_some() {
+                return Err(eyre!(
+                    "`fees.base_fee_timeout_secs` requires `fees.max_base_fee`"
+                ));
+            }
+            return Ok(None);
+        };
+
+        let mut gate = BaseFeeGate::new(parse_amount(max_base_fee)?.try_into()?);
+        if let Some(timeout) = fees.base_fee_timeout_secs {
+            gate = gate.with_timeout(Duration::from_secs(timeout));
+        }
+
+        Ok(Some(gate))
+    }
+
+    /// The watcher of the `[launch]` condition, if any.
+    pub fn launch_watcher(&self) -> Result<Option<LaunchWatcher>> {
+        let Some(launch) = &self.launch else {
+            return Ok(None);
+        };
+
+        let contract = launch.contract.unwrap_or(self.mint.contract);
+        let condition =
+            match launch.condition {
+                LaunchKind::Code => LaunchCondition::Code(contract),
+                LaunchKind::View => LaunchCondition::View {
+                    contract,
+                    function: launch.function.clone().ok_or_else(|| {
+                        eyre!("the `view` launch condition requires `launch.function`")
+                    })?,
+                },
+                LaunchKind::Event => {
+                    let event = launch.event.as_deref().ok_or_else(|| {
+                        eyre!("the `event` launch condition requires `launch.event`")
+                    })?;
+                    LaunchCondition::event(contract, event)?
+                }
+                LaunchKind::Block => LaunchCondition::Block(launch.block.ok_or_else(|| {
+                    eyre!("the `block` launch condition requires `launch.block`")
+                })?),
+                LaunchKind::Timestamp => {
+                    LaunchCondition::Timestamp(launch.timestamp.ok_or_else(|| {
+                        eyre!("the `timestamp` launch condition requires `launch.timestamp`")
+                    })?)
+                }
+            };
+        if launch.poll_interval_ms == 0 {
+            return Err(eyre!("`launch.poll_interval_ms` must be greater than zero"));
+        }
+
+        let mut watcher = LaunchWatcher::new(condition)
+            .with_poll_interval(Duration::from_millis(launch.poll_interval_ms));
+        if let Some(timeout_secs) = launch.timeout_secs {
+            watcher = watcher.with_timeout(Duration::from_secs(timeout_secs));
+        }
+
+        Ok(Some(watcher))
+    }
+
+    /// Loads the ABI of the mint contract.
+    pub fn mint_abi(&self) -> Result<JsonAbi> {
+        match &self.mint.abi {
+            Some(path) => load_abi(self.resolve_path(path)),
+            None => parse_signatures(self.mint.signatures.iter().map(String::as_str)),
+        }
+    }
+
+    /// Returns the price of the mint, if the campaign has one.
+    pub fn mint_price(&self) -> Result<Option<MintPrice>> {
+        match (&self.mint.value, &self.mint.price_function) {
+            (Some(value), _) => Ok(Some(MintPrice::Fixed(parse_amount(value)?))),
+            (None, Some(function_name)) => Ok(Some(MintPrice::View(function_name.clone()))),
+            (None, None) => Ok(None),
+        }
+    }
+
+    /// Parses the raw calldata of the mint call, if the campaign has one.
+    pub fn mint_calldata(&self) -> Result<Option<Bytes>> {
+        self.mint
+            .calldata
+            .as_ref()
+            .map(|calldata| {
+                calldata
+                    .parse()
+                    .wrap_err_with(|| format!("`mint.calldata` is not valid hex: `{calldata}`"))
+            })
+            .transpose()
+    }
+
+    /// Resolves a path of the campaign against the directory of the campaign file.
+    pub fn resolve_path(&self, path: &Path) -> PathBuf {
+        self.base_dir.join(path)
+    }
+
+    /// Reads the mnemonic of the minting accounts.
+    pub fn mnemonic(&self) -> Result<SecretString> {
+        load_secret(
+            "accounts.mnemonic",
+            self.accounts.mnemonic_env.as_ref(),
+            self.accounts.mnemonic_file.as_ref(),
+            &self.base_dir,
+        )
+    }
+
+    /// Reads the private key of the funder, if the campaign has a `[funding]` section.
+    pub fn funder_key(&self) -> Result<Option<SecretString>> {
+        self.funding
+            .as_ref()
+            .map(|funding| {
+                load_secret(
+                    "funding.sender_key",
+                    funding.sender_key_env.as_ref(),
+                    funding.sender_key_file.as_ref(),
+                    &self.base_dir,
+                )
+            })
+            .transpose()
+    }
+
+    /// Builds the bundle relay, reading its auth key, if the campaign has a `[relay]` section.
+    pub fn bundle_relay(&self) -> Result<Option<BundleRelay>> {
+        let Some(relay) = &self.relay else {
+            return Ok(None);
+        };
+
+        let url = relay
+            .url
+            .parse()
+            .wrap_err_with(|| format!("invalid relay URL `{}`", relay.url))?;
+        let auth_signer = load_secret(
+            "relay.auth_key",
+            relay.auth_key_env.as_ref(),
+            relay.auth_key_file.as_ref(),
+            &self.base_dir,
+        )?
+        .to_signer()?;
+
+        Ok(Some(
+            BundleRelay::new(url, auth_signer)
+                .with_method(relay.method)
+                .with_max_blocks(relay.max_blocks),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mint::DEFAULT_LOG_CHUNK_SIZE;
+
+    const CAMPAIGN: &str = r#"
+        [network]
+        profile = "anvil"
+        rpc_urls = ["http://127.0.0.1:8545"]
+
+        [accounts]
+        mnemonic_env = "MNEMONIC"
+        start_index = 100
+        end_index = 200
+
+        [funding]
+        distributor = "0x5FbDB2315678afecb367f032d93F642f64180aa3"
+        sender_key_env = "FUNDER_KEY"
+        amount = "0.001 ether"
+        strategy = "top_up"
+
+        [mint]
+        contract = "0xe7f1725E7734CE288F8367e1Bb143E90bb3F0512"
+        abi = "contracts/out/FreeMint.sol/FreeMint.json"
+        concurrency = 4
+
+        [fees]
+        strategy = "fixed"
+        max_fee_per_gas = "30 gwei"
+        max_priority_fee_per_gas = "1 gwei"
+    "#;
+
+    #[test]
+    fn test_parse_campaign() {
+        let config = CampaignConfig::from_toml_str(CAMPAIGN).unwrap();
+
+        assert_eq!(config.network_profile().unwrap().chain_id, 31337);
+        assert_eq!(config.mint.function, "mint");
+        assert_eq!(
+            config.funding.as_ref().unwrap().strategy,
+            FundingStrategy::TopUp
+        );
+        assert_eq!(
+            config.fee_strategy().unwrap(),
+            Some(FeeStrategy::Fixed {
+                max_fee_per_gas: 30_000_000_000,
+                max_priority_fee_per_gas: 1_000_000_000,
+            })
+        );
+    }
+
+    #[test]
+    fn test_base_fee_gate() {
+        let config = CampaignConfig::from_toml_str(CAMPAIGN).unwrap();
+        assert_eq!(config.base_fee_gate().unwrap(), None);
+
+        let campaign = CAMPAIGN.replace(
+            "max_priority_fee_per_gas = \"1 gwei\"",
+            "max_priority_fee_per_gas = \"1 gwei\"\nmax_base_fee = \"20 gwei\"\nbase_fee_timeout_secs = 600",
+        );
+        let gate = CampaignConfig::from_toml_str(&campaign)
+            .unwrap()
+            .base_fee_gate()
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(gate.max_base_fee, 20_000_000_000);
+        assert_eq!(gate.timeout, Some(Duration::from_secs(600)));
+    }
+
+    #[test]
+    fn test_metrics() {
+        let campaign = format!("{CAMPAIGN}\n[metrics]\nlisten = \"127.0.0.1:9898\"");
+        let parsed = CampaignConfig::from_toml_str(&campaign);
+
+        if cfg!(feature = "metrics") {
+            let listen = parsed.unwrap().metrics.unwrap().listen;
+            assert_eq!(listen, "127.0.0.1:9898".parse().unwrap());
+        } else {
+            assert!(parsed.is_err());
+        }
+
+        let campaign = format!("{CAMPAIGN}\n[metrics]\nlisten = \"localhost\"");
+        assert!(CampaignConfig::from_toml_str(&campaign).is_err());
+    }
+
+    #[test]
+    fn test_invalid_campaigns() {
+        let cases = [
+            ("start_index = 100", "start_index = 300"),
+            ("mnemonic_env = \"MNEMONIC\"", ""),
+            ("profile = \"anvil\"", "profile = \"unknown\""),
+            ("amount = \"0.001 ether\"", "amount = \"lots\""),
+            ("amount = \"0.001 ether\"", ""),
+            (
+                "concurrency = 4",
+                "concurrency = 4\nvalue = \"1 gwei\"\nprice_function = \"mintPrice\"",
+            ),
+            ("concurrency = 4", "concurrency = 0"),
+            ("max_fee_per_gas = \"30 gwei\"", ""),
+            ("rpc_urls = [\"http://127.0.0.1:8545\"]", "rpc_urls = []"),
+            (
+                "rpc_urls = [\"http://127.0.0.1:8545\"]",
+                "rpc_urls = [\"http://127.0.0.1:8545\"]\nconfirmations = 0",
+            ),
+            ("concurrency = 4", "concurrency = 4\ngas_budget = \"a lot\""),
+            (
+                "max_priority_fee_per_gas = \"1 gwei\"",
+                "max_priority_fee_per_gas = \"1 gwei\"\nbase_fee_timeout_secs = 60",
+            ),
+            (
+                "concurrency = 4",
+                "concurrency = 4\nargs = [\"1\"]\nargs_file = \"args.json\"",
+            ),
+            (
+                "concurrency = 4",
+                "concurrency = 4\nsignatures = [\"function mint()\"]",
+            ),
+        ];
+
+        for (from, to) in cases {
+            let campaign = CAMPAIGN.replace(from, to);
+            assert!(
+                CampaignConfig::from_toml_str(&campaign).is_err(),
+                "`{to}` should be rejected"
+            );
+        }
+    }
+
+    #[test]
+    fn test_mint_signatures() {
+        let campaign = CAMPAIGN.replace(
+            "abi = \"contracts/out/FreeMint.sol/FreeMint.json\"",
+            "signatures = [\"function mint()\"]",
+        );
+        let config = CampaignConfig::from_toml_str(&campaign).unwrap();
+
+        assert!(config.mint_abi().unwrap().function("mint").is_some());
+    }
+
+    #[test]
+    fn test_mint_calldata() {
+        let abi = "abi = \"contracts/out/FreeMint.sol/FreeMint.json\"";
+        let config =
+            CampaignConfig::from_toml_str(&CAMPAIGN.replace(abi, "calldata = \"0x1249c58b\""))
+                .unwrap();
+        assert_eq!(
+            config.mint_calldata().unwrap().unwrap().as_ref(),
+            &[0x12, 0x49, 0xc5, 0x8b]
+        );
+
+        for calldata in ["calldata = \"0xzz\"", "calldata = \"0x12\"\nargs = [\"1\"]"] {
+            assert!(CampaignConfig::from_toml_str(&CAMPAIGN.replace(abi, calldata)).is_err());
+        }
+    }
+
+    #[test]
+    fn test_mint_price() {
+        let campaign = CAMPAIGN
+            .replace("strategy = \"top_up\"", "strategy = \"mint_cost\"")
+            .replace("amount = \"0.001 ether\"", "")
+            .replace("concurrency = 4", "price_function = \"mintPrice\"");
+        let config = CampaignConfig::from_toml_str(&campaign).unwrap();
+
+        assert_eq!(
+            config.mint_price().unwrap(),
+            Some(MintPrice::View("mintPrice".to_owned()))
+        );
+
+        let campaign = CAMPAIGN.replace("concurrency = 4", "value = \"0.01 ether\"");
+        let config = CampaignConfig::from_toml_str(&campaign).unwrap();
+
+        assert_eq!(
+            config.mint_price().unwrap(),
+            Some(MintPrice::Fixed(U256::from(10u64.pow(16))))
+        );
+    }
+
+    #[test]
+    fn test_mint_supply() {
+        let campaign = CAMPAIGN.replace(
+            "concurrency = 4",
+            "concurrency = 4\n[mint.supply]\ntotal_supply_function = \"minted\"",
+        );
+        let supply = CampaignConfig::from_toml_str(&campaign)
+            .unwrap()
+            .mint
+            .supply
+            .unwrap();
+
+        assert_eq!(supply.total_supply_function, "minted");
+        assert_eq!(supply.max_supply_function, "MAX_SUPPLY");
+    }
+
+    #[test]
+    fn test_launch() {
+        let campaign = format!(
+            "{CAMPAIGN}\n[launch]\ncondition = \"view\"\nfunction = \"mintEnabled\"\ntimeout_secs = 60"
+        );
+        let config = CampaignConfig::from_toml_str(&campaign).unwrap();
+        let watcher = config.launch_watcher().unwrap().unwrap();
+
+        assert_eq!(
+            watcher.condition,
+            LaunchCondition::View {
+                contract: config.mint.contract,
+                function: "mintEnabled".to_owned(),
+            }
+        );
+        assert_eq!(watcher.timeout, Some(Duration::from_secs(60)));
+
+        let campaign = format!("{CAMPAIGN}\n[launch]\ncondition = \"block\"");
+        assert!(CampaignConfig::from_toml_str(&campaign).is_err());
+    }
+
+    #[test]
+    fn test_relay() {
+        let key = "ac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80";
+        std::env::set_var("STORMINT_TEST_RELAY_KEY", key);
+
+        let campaign = format!(
+            "{CAMPAIGN}\n[relay]\nurl = \"http://localhost:8545\"\nauth_key_env = \"STORMINT_TEST_RELAY_KEY\"\nmethod = \"private_transaction\""
+        );
+        let relay = CampaignConfig::from_toml_str(&campaign)
+            .unwrap()
+            .bundle_relay()
+            .unwrap()
+            .unwrap();
+        assert_eq!(relay.method, RelayMethod::PrivateTransaction);
+        assert_eq!(relay.max_blocks, DEFAULT_RELAY_BLOCKS);
+
+        let campaign = format!("{CAMPAIGN}\n[relay]\nurl = \"http://localhost:8545\"");
+        assert!(CampaignConfig::from_toml_str(&campaign).is_err());
+    }
+
+    #[test]
+    fn test_mint_skip_minted() {
+        let campaign = CAMPAIGN.replace(
+            "concurrency = 4",
+            "concurrency = 4\n[mint.skip_minted]\nfrom_block = 100",
+        );
+        let scan = CampaignConfig::from_toml_str(&campaign)
+            .unwrap()
+            .mint
+            .skip_minted
+            .unwrap();
+
+        assert_eq!(scan.from_block, 100);
+        assert_eq!(scan.chunk_size, DEFAULT_LOG_CHUNK_SIZE);
+    }
+
+    #[test]
+    fn test_parse_amount() {
+        assert_eq!(
+            parse_amount("0.001 ether").unwrap(),
+            U256::from(10u64.pow(15))
+        );
+        assert_eq!(parse_amount("42").unwrap(), U256::from(42));
+        assert!(parse_amount("1 ether extra").is_err());
+        assert!(parse_amount("-1 ether").is_err());
+        assert!(parse_amount("-1").is_err());
+    }
+}
@@ -0,0 +1,9 @@
+mod config;
+pub use config::{
+    parse_amount, AccountsConfig, CampaignConfig, FeeKind, FeesConfig, FundingConfig,
+    FundingStrategy, LaunchConfig, LaunchKind, MetricsConfig, MintConfig, NetworkConfig,
+    RelayConfig,
+};
+
+mod runner;
+pub use runner::{run_campaign, run_campaign_with, CampaignReport};
@@ -0,0 +1,394 @@
+use super::{parse_amount, CampaignConfig, FundingStrategy};
+use crate::{
+    account::generate_accounts,
+    distributor::{
+        deploy_deterministic, distribute_batch, top_up_params, DistributeParam, DISTRIBUTOR_SALT,
+    },
+    executor::{CallRequest, EventSender, ExecuteOptions, Finality, RunControl},
+    launch::mint_on_launch,
+    mint::{
+        coerce_args, coerce_json_args, estimate_mint_gas, expand_args, expand_json_args, mint_cost,
+        mint_loop_with_options, MintCall, MintEncoder, MintOptions, MintResult, SignerArgs,
+        DEFAULT_MINT_GAS_LIMIT, INDEX_PLACEHOLDER, SIGNER_PLACEHOLDER,
+    },
+    network::{http_provider, NetworkProfile},
+};
+use alloy::{
+    primitives::{Address, TxHash, U256},
+    providers::Provider,
+    signers::local::PrivateKeySigner,
+    transports::http::reqwest::Url,
+};
+use eyre::{eyre, Result};
+
+/// The outcome of a campaign run.
+///
+/// # Fields
+///
+/// * `rpc_http` - The RPC endpoint the campaign ran against.
+/// * `network` - The network profile the campaign ran against.
+/// * `funding` - The hashes of the funding transactions.
+/// * `results` - The results of the mint operations.
+#[derive(Debug)]
+pub struct CampaignReport {
+    pub rpc_http: Url,
+    pub network: NetworkProfile,
+    pub funding: Vec<TxHash>,
+    pub results: Vec<MintResult>,
+}
+
+/// Runs a whole campaign: generate accounts, fund them, then mint with every one of them.
+///
+/// Everything that can fail without sending a transaction, such as reading secrets, loading
+/// the ABI, parsing the arguments or checking the chain id, is done before the first
+/// transaction is sent.
+///
+/// # Arguments
+///
+/// * `config` - The campaign to run.
+///
+/// # Returns
+///
+/// * `Result<CampaignReport>` - The outcome of the campaign on success.
+pub async fn run_campaign(config: &CampaignConfig) -> Result<CampaignReport> {
+    run_campaign_with(config, None, None).await
+}
+
+/// Runs a whole campaign like [`run_campaign`], reporting its transactions and following a
+/// run control, e.g. to drive the dashboard.
+///
+/// # Arguments
+///
+/// * `config` - The campaign to run.
+/// * `events` - Where to report the lifecycle of the funding and mint transactions, if anywhere.
+/// * `control` - The control that pauses or aborts the run, if any.
+///
+/// # Returns
+///
+/// * `Result<CampaignReport>` - The outcome of the campaign on success.
+pub async fn run_campaign_with(
+    config: &CampaignConfig,
+    events: Option<EventSender>,
+    control: Option<RunControl>,
+) -> Result<CampaignReport> {
+    config.validate()?;
+
+    // the endpoint lives as long as the run
+    #[cfg(feature = "metrics")]
+    let _metrics = match &config.metrics {
+        Some(metrics) => Some(crate::metrics::MetricsServer::bind(metrics.listen).await?),
+        None => None,
+    };
+
+    let network = config.network_profile()?;
+    let rpc_http = select_endpoint(&config.rpc_urls()?, &network).await?;
+    let execute = ExecuteOptions {
+        network: Some(network.clone()),
+        fee_strategy: config.fee_strategy()?,
+        relay: None,
+        finality: config.network.confirmations.map(Finality::new),
+        base_fee_gate: config.base_fee_gate()?,
+        events,
+        control,
+    };
+
+    let value = match config.mint_price()? {
+        Some(price) => Some(
+            price
+                .resolve(rpc_http.clone(), config.mint.contract)
+                .await?,
+        ),
+        None => None,
+    };
+    let call = mint_call(config, value)?;
+    let funder = config
+        .funder_key()?
+        .map(|key| key.to_signer())
+        .transpose()?;
+    let relay = config.bundle_relay()?;
+
+    let mut accounts = {
+        let mnemonic = config.mnemonic()?;
+        generate_accounts(
+            mnemonic.expose(),
+            config.accounts.start_index,
+            config.accounts.end_index,
+        )?
+    };
+
+    // accounts that already minted would only revert, so they are neither funded nor used
+    let mut indexes: Vec<usize> = (0..accounts.len()).collect();
+    if let Some(scan) = &config.mint.skip_minted {
+        let minted = scan
+            .minted_addresses(rpc_http.clone(), config.mint.contract)
+            .await?;
+        (indexes, accounts) = indexes
+            .into_iter()
+            .zip(accounts)
+            .filter(|(_, account)| !minted.contains(&account.address()))
+            .unzip();
+    }
+    let call = Reindexed {
+        call: &*call,
+        indexes,
+    };
+
+    // per-account arguments may be missing or invalid, check them before funding
+    for (index, account) in accounts.iter().enumerate() {
+        call.encode_for(index, account.address())?;
+    }
+
+    let mut options = MintOptions {
+        execute,
+        concurrency: config.mint.concurrency,
+        check_funds: true,
+        gas_limit: config.mint.gas_limit,
+        supply: config.mint.supply.clone(),
+        gas_budget: config
+            .mint
+            .gas_budget
+            .as_deref()
+            .map(parse_amount)
+            .transpose()?,
+    };
+
+    let funding = match funder {
+        Some(funder) => {
+            let value = value.unwrap_or_default();
+            fund(config, funder, &rpc_http, &accounts, &call, value, &options).await?
+        }
+        None => Vec::new(),
+    };
+
+    // only the mints are worth hiding from the public mempool
+    options.execute.relay = relay;
+    let results = match config.launch_watcher()? {
+        Some(watcher) => {
+            mint_on_launch(&watcher, accounts, rpc_http.clone(), &call, &options).await?
+        }
+        None => mint_loop_with_options(accounts, rpc_http.clone(), &call, &options).await?,
+    };
+
+    Ok(CampaignReport {
+        rpc_http,
+        network,
+        funding,
+        results,
+    })
+}
+
+/// Picks the first reachable RPC endpoint, failing loudly if it serves another chain.
+async fn select_endpoint(rpc_urls: &[Url], network: &NetworkProfile) -> Result<Url> {
+    let mut errors = Vec::new();
+
+    for rpc_http in rpc_urls {
+        let provider = http_provider(rpc_http.clone());
+        match provider.get_chain_id().await {
+            Ok(chain_id) => {
+                network.check_chain_id(chain_id)?;
+                return Ok(rpc_http.clone());
+            }
+            Err(e) => errors.push(format!("{rpc_http}: {e}")),
+        }
+    }
+
+    Err(eyre!("no RPC endpoint is reachable: {}", errors.join("; ")))
+}
+
+/// Builds the mint call of a campaign, parsing the arguments according to the ABI.
+fn mint_call(config: &CampaignConfig, value: Option<U256>) -> Result<Box<dyn MintEncoder>> {
+    let mint = &config.mint;
+
+    if let Some(calldata) = config.mint_calldata()? {
+        return Ok(Box::new(CallRequest::raw(mint.contract, calldata, value)));
+    }
+
+    let mut call = MintCall::new(config.mint_abi()?, mint.contract).with_function(&mint.function);
+    call.value = value;
+
+    let signer_args = mint
+        .args_file
+        .as_ref()
+        .map(|path| SignerArgs::load(config.resolve_path(path)))
+        .transpose()?;
+    let templated = mint
+        .args
+        .iter()
+        .any(|arg| arg.contains(SIGNER_PLACEHOLDER) || arg.contains(INDEX_PLACEHOLDER));
+
+    if signer_args.is_none() && !templated {
+        let args = coerce_args(&call.abi, &mint.function, &mint.args)?;
+        let call = call.with_args(args);
+
+        // surface encoding errors before anything is sent
+        call.encode()?;
+
+        return Ok(Box::new(call));
+    }
+
+    let (abi, function) = (call.abi.clone(), mint.function.clone());
+    let (template, start_index) = (mint.args.clone(), config.accounts.start_index as usize);
+
+    Ok(Box::new(call.with_args_fn(move |signer, index| {
+        let index = start_index + index;
+        match &signer_args {
+            Some(signer_args) => coerce_json_args(
+                &abi,
+                &function,
+                &expand_json_args(signer_args.get(signer)?, signer, index),
+            ),
+            None => coerce_args(&abi, &function, &expand_args(&template, signer, index)),
+        }
+    })))
+}
+
+/// Encodes the call of an account by its position among all generated accounts, so that
+/// `{index}` stays the derivation index once some accounts are filtered out.
+struct Reindexed<'a> {
+    call: &'a dyn MintEncoder,
+    indexes: Vec<usize>,
+}
+
+impl MintEncoder for Reindexed<'_> {
+    fn encode_for(&self, index: usize, signer: Address) -> Result<CallRequest> {
+        self.call.encode_for(self.indexes[index], signer)
+    }
+}
+
+/// Funds the minting accounts through the `Distributor` contract.
+///
+/// Without a configured address, the deterministic deployment is used and deployed if missing.
+async fn fund(
+    config: &CampaignConfig,
+    funder: PrivateKeySigner,
+    rpc_http: &Url,
+    accounts: &[PrivateKeySigner],
+    call: &dyn MintEncoder,
+    mint_value: U256,
+    options: &MintOptions,
+) -> Result<Vec<TxHash>> {
+    let Some(funding) = &config.funding else {
+        return Ok(Vec::new());
+    };
+
+    let amount = funding
+        .amount
+        .as_deref()
+        .map(parse_amount)
+        .transpose()?
+        .unwrap_or_default();
+    let receivers: Vec<Address> = accounts.iter().map(|account| account.address()).collect();
+
+    let params: Vec<DistributeParam> = match funding.strategy {
+        FundingStrategy::Fixed => receivers
+            .into_iter()
+            .map(|receiver| DistributeParam { receiver, amount })
+            .collect(),
+        FundingStrategy::TopUp => top_up_params(rpc_http.clone(), &receivers, amount).await?,
+        FundingStrategy::MintCost => {
+            let fee_strategy = options.execute.fee_strategy();
+            let max_fee_per_gas = fee_strategy.max_fee_per_gas(rpc_http.clone()).await?;
+            let gas_limit = options.gas_limit.unwrap_or(DEFAULT_MINT_GAS_LIMIT);
+            let provider = http_provider(rpc_http.clone());
+
+            let mut params = Vec::new();
+            for (index, &receiver) in receivers.iter().enumerate() {
+                let request = call.encode_for(index, receiver)?;
+                let gas = estimate_mint_gas(&provider, receiver, &request, gas_limit).await;
+                let target = mint_cost(mint_value, gas, max_fee_per_gas) + amount;
+                params.extend(top_up_params(rpc_http.clone(), &[receiver], target).await?);
+            }
+            params
+        }
+    };
+
+    if params.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let distributor = match funding.distributor {
+        Some(distributor) => distributor,
+        None => deploy_deterministic(funder.clone(), rpc_http.clone(), DISTRIBUTOR_SALT).await?,
+    };
+
+    let mut tx_hashes = Vec::new();
+    for (index, batch) in params.chunks(funding.batch_size).enumerate() {
+        let tx_hash = distribute_batch(
+            funder.clone(),
+            rpc_http.clone(),
+            distributor,
+            batch,
+            &options.execute,
+            index,
+        )
+        .await?;
+        tx_hashes.push(tx_hash);
+    }
+
+    Ok(tx_hashes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy::{dyn_abi::DynSolValue, json_abi::JsonAbi};
+
+    const CAMPAIGN: &str = r#"
+        [network]
+        profile = "anvil"
+        rpc_urls = ["http://127.0.0.1:8545"]
+
+        [accounts]
+        mnemonic_env = "MNEMONIC"
+        start_index = 100
+        end_index = 200
+
+        [mint]
+        contract = "0xe7f1725E7734CE288F8367e1Bb143E90bb3F0512"
+        signatures = ["function mint(address to, uint256 id)"]
+        args = ["{signer}", "{index}"]
+    "#;
+
+    #[test]
+    fn test_templated_mint_call() {
+        let config = CampaignConfig::from_toml_str(CAMPAIGN).unwrap();
+        let call = mint_call(&config, None).unwrap();
+
+        let signer = Address::repeat_byte(1);
+        let expected = MintCall::new(config.mint_abi().unwrap(), config.mint.contract)
+            .with_args(vec![
+                DynSolValue::from(signer),
+                DynSolValue::from(U256::from(102)),
+            ])
+            .encode()
+            .unwrap();
+
+        assert_eq!(call.encode_for(2, signer).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_static_mint_call() {
+        let campaign = CAMPAIGN.replace(
+            "\"{signer}\", \"{index}\"",
+            "\"0x0000000000000000000000000000000000000001\", \"7\"",
+        );
+        let config = CampaignConfig::from_toml_str(&campaign).unwrap();
+        let call = mint_call(&config, None).unwrap();
+
+        let abi = JsonAbi::parse(["function mint(address to, uint256 id)"]).unwrap();
+        let expected = MintCall::new(abi, config.mint.contract)
+            .with_args(vec![
+                DynSolValue::from(Address::with_last_byte(1)),
+                DynSolValue::from(U256::from(7)),
+            ])
+            .encode()
+            .unwrap();
+
+        // the same call for every signer
+        assert_eq!(call.encode_for(0, Address::ZERO).unwrap(), expected);
+        assert_eq!(
+            call.encode_for(5, Address::repeat_byte(9)).unwrap(),
+            expected
+        );
+    }
+}
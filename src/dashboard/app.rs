@@ -0,0 +1,285 @@
+use super::{view::render, DashboardState};
+use crate::executor::{RunControl, TxEvent};
+use crate::network::http_provider;
+use alloy::{
+    eips::BlockNumberOrTag, providers::Provider, rpc::types::BlockTransactionsKind,
+    transports::http::reqwest::Url,
+};
+use console::{Key, Term};
+use eyre::Result;
+use std::{
+    io::IsTerminal,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    thread::{self, JoinHandle},
+    time::Duration,
+};
+use tokio::sync::mpsc::{self, UnboundedReceiver, UnboundedSender};
+
+/// The default interval between two frames, and two reads of the base fee.
+pub const DEFAULT_REFRESH_INTERVAL: Duration = Duration::from_secs(1);
+
+/// How much the fee caps are raised per key press, in percent.
+pub const FEE_RAISE_PERCENT: u64 = 10;
+
+/// How long the key reader waits for a key before checking whether the dashboard returned.
+const KEY_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Switches to the alternate screen of the terminal.
+const ENTER_ALTERNATE_SCREEN: &str = "\x1b[?1049h";
+
+/// Switches back to the main screen of the terminal, as it was before.
+const LEAVE_ALTERNATE_SCREEN: &str = "\x1b[?1049l";
+
+/// A full-screen terminal dashboard following a run from its event channel.
+///
+/// The dashboard draws with plain ANSI sequences, so it works over SSH, and steers the run
+/// through its [`RunControl`]: `p` pauses or resumes, `+` raises the fee caps by
+/// [`FEE_RAISE_PERCENT`] and `q` aborts.
+///
+/// # Fields
+///
+/// * `events` - The receiving half of the event channel of the run.
+/// * `rpc_http` - The HTTP URL of the Ethereum RPC endpoint, to read the base fee.
+/// * `control` - The control set on the options of the run.
+/// * `state` - What is shown, folded from the events.
+/// * `refresh_interval` - The interval between two frames.
+#[derive(Debug)]
+pub struct Dashboard {
+    pub events: UnboundedReceiver<TxEvent>,
+    pub rpc_http: Url,
+    pub control: RunControl,
+    pub state: DashboardState,
+    pub refresh_interval: Duration,
+}
+
+impl Dashboard {
+    /// Creates a new `Dashboard` for a run over `accounts` accounts.
+    pub fn new(
+        events: UnboundedReceiver<TxEvent>,
+        rpc_http: Url,
+        control: RunControl,
+        accounts: usize,
+    ) -> Self {
+        Self {
+            events,
+            rpc_http,
+            control,
+            state: DashboardState::new(accounts),
+            refresh_interval: DEFAULT_REFRESH_INTERVAL,
+        }
+    }
+
+    /// Sets the interval between two frames.
+    pub fn with_refresh_interval(mut self, refresh_interval: Duration) -> Self {
+        self.refresh_interval = refresh_interval;
+        self
+    }
+
+    /// Shows the dashboard until every account is done or the event channel is closed.
+    ///
+    /// The dashboard is drawn on the alternate screen, so the terminal shows what it did before
+    /// once it returns. Keys are read on a separate thread, stopped before returning; the
+    /// terminal is restored on every path, errors included.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<DashboardState>` - The final state, or an error if the terminal failed.
+    pub async fn run(mut self) -> Result<DashboardState> {
+        let term = Term::buffered_stdout();
+        // the reader is dropped, and so stopped, before the guard restores the terminal
+        let _guard = TerminalGuard::enter(&term)?;
+        let (key_sender, mut keys) = mpsc::unbounded_channel();
+        let _reader = (term.is_term() && std::io::stdin().is_terminal())
+            .then(|| KeyReader::spawn(key_sender));
+
+        let provider = http_provider(self.rpc_http.clone());
+        let mut refresh = tokio::time::interval(self.refresh_interval);
+
+        let mut open = true;
+        while open && !self.state.is_complete() {
+            tokio::select! {
+                event = self.events.recv() => match event {
+                    Some(event) => self.state.apply(&event),
+                    None => open = false,
+                },
+                Some(key) = keys.recv() => self.handle_key(key),
+                _ = refresh.tick() => {
+                    // a failed read keeps the last known base fee
+                    let block = provider
+                        .get_block_by_number(BlockNumberOrTag::Latest, BlockTransactionsKind::Hashes)
+                        .await;
+                    if let Ok(Some(block)) = block {
+                        self.state.base_fee = block.header.base_fee_per_gas.map(u128::from);
+                    }
+                    self.draw(&term)?;
+                }
+            }
+        }
+
+        Ok(self.state)
+    }
+
+    /// Applies a key press to the run.
+    fn handle_key(&self, key: Key) {
+        match key {
+            Key::Char('p') => {
+                self.control.toggle_pause();
+            }
+            Key::Char('+') => {
+                self.control.raise_fees(FEE_RAISE_PERCENT);
+            }
+            Key::Char('q') | Key::Escape => self.control.abort(),
+            _ => {}
+        }
+    }
+
+    /// Draws a frame over the previous one.
+    fn draw(&self, term: &Term) -> Result<()> {
+        let (height, width) = term.size();
+        let lines = render(
+            &self.state,
+            &self.control,
+            usize::from(width),
+            usize::from(height),
+        );
+
+        term.move_cursor_to(0, 0)?;
+        for line in lines {
+            term.clear_line()?;
+            term.write_line(&line)?;
+        }
+        term.clear_to_end_of_screen()?;
+        term.flush()?;
+
+        Ok(())
+    }
+}
+
+/// Takes over the terminal for the dashboard and gives it back when dropped.
+///
+/// On Unix, the input is switched out of line mode without echo, so that single keys can be
+/// polled without being printed.
+struct TerminalGuard {
+    term: Term,
+    #[cfg(unix)]
+    termios: Option<rustix::termios::Termios>,
+}
+
+impl TerminalGuard {
+    fn enter(term: &Term) -> Result<Self> {
+        let guard = Self {
+            term: term.clone(),
+            #[cfg(unix)]
+            termios: key_mode(),
+        };
+        term.write_str(ENTER_ALTERNATE_SCREEN)?;
+        term.hide_cursor()?;
+        term.flush()?;
+
+        Ok(guard)
+    }
+}
+
+impl Drop for TerminalGuard {
+    fn drop(&mut self) {
+        // the terminal is given back as well as possible, whatever fails
+        #[cfg(unix)]
+        if let Some(termios) = &self.termios {
+            use rustix::termios::{tcsetattr, OptionalActions};
+            let _ = tcsetattr(rustix::stdio::stdin(), OptionalActions::Now, termios);
+        }
+        let _ = self.term.show_cursor();
+        let _ = self.term.write_str(LEAVE_ALTERNATE_SCREEN);
+        let _ = self.term.flush();
+    }
+}
+
+/// Turns off line mode and echo on the standard input, if it is a terminal.
+///
+/// # Returns
+///
+/// * `Option<Termios>` - The previous settings, to restore, if they were changed.
+#[cfg(unix)]
+fn key_mode() -> Option<rustix::termios::Termios> {
+    use rustix::termios::{tcgetattr, tcsetattr, LocalModes, OptionalActions};
+
+    let stdin = rustix::stdio::stdin();
+    let original = tcgetattr(stdin).ok()?;
+    let mut termios = original.clone();
+    termios.local_modes -= LocalModes::ICANON | LocalModes::ECHO;
+    tcsetattr(stdin, OptionalActions::Now, &termios).ok()?;
+
+    Some(original)
+}
+
+/// Reads keys on a separate thread until dropped.
+///
+/// The thread waits at most [`KEY_POLL_INTERVAL`] for a key before checking whether it must
+/// stop, so dropping the reader returns quickly and no key is read after it.
+struct KeyReader {
+    shutdown: Arc<AtomicBool>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl KeyReader {
+    fn spawn(keys: UnboundedSender<Key>) -> Self {
+        let shutdown = Arc::new(AtomicBool::new(false));
+        let stop = Arc::clone(&shutdown);
+        let handle = thread::spawn(move || {
+            let term = Term::stdout();
+            while !stop.load(Ordering::Relaxed) {
+                match key_ready(KEY_POLL_INTERVAL) {
+                    Ok(false) => continue,
+                    Ok(true) => {}
+                    Err(_) => break,
+                }
+                let sent = term.read_key().map(|key| keys.send(key).is_ok());
+                if !matches!(sent, Ok(true)) {
+                    break;
+                }
+            }
+        });
+
+        Self {
+            shutdown,
+            handle: Some(handle),
+        }
+    }
+}
+
+impl Drop for KeyReader {
+    fn drop(&mut self) {
+        self.shutdown.store(true, Ordering::Relaxed);
+        // elsewhere the read cannot be interrupted, so the thread ends at the next key
+        #[cfg(unix)]
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+/// Waits up to `timeout` for a key on the standard input.
+#[cfg(unix)]
+fn key_ready(timeout: Duration) -> std::io::Result<bool> {
+    use rustix::event::{poll, PollFd, PollFlags};
+
+    let stdin = rustix::stdio::stdin();
+    let mut fds = [PollFd::new(&stdin, PollFlags::IN)];
+    let timeout = i32::try_from(timeout.as_millis()).unwrap_or(i32::MAX);
+    match poll(&mut fds, timeout) {
+        Ok(ready) => Ok(ready > 0),
+        Err(rustix::io::Errno::INTR) => Ok(false),
+        Err(error) => Err(error.into()),
+    }
+}
+
+/// Waits up to `timeout` for a key on the standard input.
+///
+/// Keys cannot be polled here, so the next read blocks until one is pressed.
+#[cfg(not(unix))]
+fn key_ready(_timeout: Duration) -> std::io::Result<bool> {
+    Ok(true)
+}
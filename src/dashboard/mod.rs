@@ -0,0 +1,7 @@
+mod state;
+pub use state::{AccountState, DashboardState};
+
+mod view;
+
+mod app;
+pub use app::{Dashboard, DEFAULT_REFRESH_INTERVAL, FEE_RAISE_PERCENT};
@@ -0,0 +1,241 @@
+use crate::executor::{TxEvent, TxEventKind};
+use alloy::primitives::{Address, U256};
+use std::{
+    collections::{BTreeMap, HashMap},
+    time::{Duration, Instant},
+};
+
+/// The latest event of an account, or of a batch it sends.
+///
+/// # Fields
+///
+/// * `signer` - The account.
+/// * `batch` - The batch, for a transaction acting for several accounts, see [`TxEvent::batch`].
+/// * `last` - What happened to its transaction last.
+/// * `updated` - When it happened, since the dashboard started.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccountState {
+    pub signer: Address,
+    pub batch: Option<usize>,
+    pub last: TxEventKind,
+    pub updated: Duration,
+}
+
+/// Everything the dashboard shows, folded from the events of a run.
+///
+/// The outcome counters only cover the own transactions of accounts; batches, e.g. funding
+/// chunks, are counted in `batches` so that they never complete the run.
+///
+/// # Fields
+///
+/// * `accounts` - The number of accounts of the run.
+/// * `confirmed` - The transactions confirmed.
+/// * `reverted` - The transactions included but reverted.
+/// * `failed` - The transactions that could not be sent or included.
+/// * `skipped` - The accounts for which nothing was sent.
+/// * `batches` - The batches done, whatever their outcome.
+/// * `retried` - How many times a transaction was sent again after a reorg.
+/// * `errors` - The failures of accounts and batches per
+///   [`ErrorClass`](crate::executor::ErrorClass) name.
+/// * `gas_spent` - What the included transactions paid for gas, in wei.
+/// * `base_fee` - The base fee of the latest block, in wei, if it could be read.
+#[derive(Debug, Clone)]
+pub struct DashboardState {
+    pub accounts: usize,
+    pub confirmed: usize,
+    pub reverted: usize,
+    pub failed: usize,
+    pub skipped: usize,
+    pub batches: usize,
+    pub retried: usize,
+    pub errors: BTreeMap<&'static str, usize>,
+    pub gas_spent: U256,
+    pub base_fee: Option<u128>,
+    started: Instant,
+    entries: Vec<AccountState>,
+    positions: HashMap<(Address, Option<usize>), usize>,
+}
+
+impl DashboardState {
+    /// Creates the state of a run over `accounts` accounts.
+    pub fn new(accounts: usize) -> Self {
+        Self {
+            accounts,
+            confirmed: 0,
+            reverted: 0,
+            failed: 0,
+            skipped: 0,
+            batches: 0,
+            retried: 0,
+            errors: BTreeMap::new(),
+            gas_spent: U256::ZERO,
+            base_fee: None,
+            started: Instant::now(),
+            entries: Vec::new(),
+            positions: HashMap::new(),
+        }
+    }
+
+    /// Folds an event into the state.
+    pub fn apply(&mut self, event: &TxEvent) {
+        let account = event.is_account();
+        match &event.kind {
+            TxEventKind::Confirmed { gas_cost, .. } => {
+                self.confirmed += usize::from(account);
+                self.gas_spent += gas_cost;
+            }
+            TxEventKind::Reverted { gas_cost, .. } => {
+                self.reverted += usize::from(account);
+                self.gas_spent += gas_cost;
+            }
+            TxEventKind::Failed { class, .. } => {
+                self.failed += usize::from(account);
+                *self.errors.entry(class.as_str()).or_default() += 1;
+            }
+            TxEventKind::Skipped { .. } => self.skipped += usize::from(account),
+            TxEventKind::Retried { .. } => self.retried += 1,
+            TxEventKind::Submitted { .. } | TxEventKind::Pending { .. } => {}
+        }
+        if !account && event.kind.is_final() {
+            self.batches += 1;
+        }
+
+        let state = AccountState {
+            signer: event.signer,
+            batch: event.batch,
+            last: event.kind.clone(),
+            updated: self.started.elapsed(),
+        };
+        let source = (event.signer, event.batch);
+        match self.positions.get(&source) {
+            Some(&position) => self.entries[position] = state,
+            None => {
+                self.positions.insert(source, self.entries.len());
+                self.entries.push(state);
+            }
+        }
+    }
+
+    /// Returns the accounts done, whatever their outcome.
+    pub fn done(&self) -> usize {
+        self.confirmed + self.reverted + self.failed + self.skipped
+    }
+
+    /// Returns the accounts whose transaction is sent and not done yet.
+    pub fn pending(&self) -> usize {
+        self.entries
+            .iter()
+            .filter(|account| account.batch.is_none() && !account.last.is_final())
+            .count()
+    }
+
+    /// Returns `true` once every account is done.
+    pub fn is_complete(&self) -> bool {
+        self.done() >= self.accounts
+    }
+
+    /// Returns the accounts done per minute since the start.
+    pub fn throughput(&self) -> f64 {
+        let minutes = self.started.elapsed().as_secs_f64() / 60.0;
+        if minutes > 0.0 {
+            self.done() as f64 / minutes
+        } else {
+            0.0
+        }
+    }
+
+    /// Returns the time since the start.
+    pub fn elapsed(&self) -> Duration {
+        self.started.elapsed()
+    }
+
+    /// Returns the accounts and batches seen so far, most recently updated first.
+    pub fn recent(&self) -> Vec<&AccountState> {
+        let mut recent: Vec<_> = self.entries.iter().collect();
+        recent.sort_by_key(|account| std::cmp::Reverse(account.updated));
+        recent
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::executor::ErrorClass;
+    use alloy::primitives::TxHash;
+
+    #[test]
+    fn test_apply_events() {
+        let mut state = DashboardState::new(3);
+        let event = |signer: u8, kind| TxEvent {
+            signer: Address::with_last_byte(signer),
+            batch: None,
+            kind,
+        };
+        let tx_hash = TxHash::with_last_byte(1);
+
+        state.apply(&event(1, TxEventKind::Submitted { tx_hash }));
+        state.apply(&event(2, TxEventKind::Submitted { tx_hash }));
+        assert_eq!(state.pending(), 2);
+
+        state.apply(&event(
+            1,
+            TxEventKind::Confirmed {
+                tx_hash,
+                block_number: Some(1),
+                gas_cost: U256::from(21_000),
+            },
+        ));
+        state.apply(&event(
+            2,
+            TxEventKind::Failed {
+                class: ErrorClass::Rpc,
+                error: "nonce too low".to_owned(),
+            },
+        ));
+        assert!(!state.is_complete());
+        state.apply(&event(
+            3,
+            TxEventKind::Skipped {
+                reason: "the run was aborted".to_owned(),
+            },
+        ));
+
+        assert!(state.is_complete());
+        assert_eq!(state.pending(), 0);
+        assert_eq!((state.confirmed, state.failed, state.skipped), (1, 1, 1));
+        assert_eq!(state.errors.get("rpc"), Some(&1));
+        assert_eq!(state.gas_spent, U256::from(21_000));
+        assert_eq!(state.recent().len(), 3);
+    }
+
+    #[test]
+    fn test_apply_batch_events() {
+        let mut state = DashboardState::new(1);
+        let funder = Address::with_last_byte(1);
+        let confirmed = TxEventKind::Confirmed {
+            tx_hash: TxHash::with_last_byte(1),
+            block_number: Some(1),
+            gas_cost: U256::from(21_000),
+        };
+
+        // two funding chunks of the same funder are neither accounts nor merged
+        for batch in 0..2 {
+            state.apply(&TxEvent {
+                signer: funder,
+                batch: Some(batch),
+                kind: confirmed.clone(),
+            });
+        }
+        assert!(!state.is_complete());
+        assert_eq!((state.confirmed, state.batches), (0, 2));
+        assert_eq!(state.gas_spent, U256::from(42_000));
+
+        state.apply(&TxEvent {
+            signer: funder,
+            batch: None,
+            kind: confirmed,
+        });
+        assert!(state.is_complete());
+        assert_eq!(state.recent().len(), 3);
+    }
+}
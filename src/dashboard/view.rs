@@ -0,0 +1,173 @@
+use super::DashboardState;
+use crate::executor::{RunControl, TxEventKind};
+use alloy::primitives::{utils::format_units, U256};
+use console::{style, truncate_str};
+
+/// The lines above the account list.
+const HEADER_LINES: usize = 6;
+
+/// Renders a frame of the dashboard, one string per terminal line.
+///
+/// # Arguments
+///
+/// * `state` - What to show.
+/// * `control` - The control of the run, for its pause, abort and fee raise.
+/// * `width` - The width of the terminal, longer lines are truncated.
+/// * `height` - The height of the terminal, the account list is cut to fit.
+pub(crate) fn render(
+    state: &DashboardState,
+    control: &RunControl,
+    width: usize,
+    height: usize,
+) -> Vec<String> {
+    let status = if control.is_aborted() {
+        style("ABORTED").red().bold()
+    } else if control.is_paused() {
+        style("PAUSED").yellow().bold()
+    } else if state.is_complete() {
+        style("DONE").green().bold()
+    } else {
+        style("RUNNING").cyan().bold()
+    };
+    let elapsed = state.elapsed().as_secs();
+
+    let mut lines = vec![
+        format!(
+            "{} {}/{} accounts done  {status}  {:02}:{:02}:{:02}",
+            style("stormint").bold(),
+            state.done(),
+            state.accounts,
+            elapsed / 3600,
+            elapsed / 60 % 60,
+            elapsed % 60
+        ),
+        format!(
+            "confirmed {}  reverted {}  failed {}  skipped {}  pending {}  retried {}  batches {}",
+            style(state.confirmed).green(),
+            style(state.reverted).red(),
+            style(state.failed).red(),
+            style(state.skipped).yellow(),
+            state.pending(),
+            state.retried,
+            state.batches
+        ),
+        format!(
+            "throughput {:.1}/min  base fee {}  gas spent {} ETH  fee caps +{}%",
+            state.throughput(),
+            state
+                .base_fee
+                .map(|base_fee| format!("{} gwei", units(U256::from(base_fee), "gwei")))
+                .unwrap_or_else(|| "-".to_owned()),
+            units(state.gas_spent, "ether"),
+            control.fee_bump_percent()
+        ),
+        match state.errors.is_empty() {
+            true => "errors: none".to_owned(),
+            false => format!(
+                "errors: {}",
+                state
+                    .errors
+                    .iter()
+                    .map(|(class, count)| format!("{class} {count}"))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+        },
+        String::new(),
+        style(format!("{:<42}  {:<10}  DETAIL", "ACCOUNT", "STATUS"))
+            .bold()
+            .to_string(),
+    ];
+
+    let rows = height.saturating_sub(HEADER_LINES + 2);
+    for account in state.recent().into_iter().take(rows) {
+        let (label, detail) = describe(&account.last);
+        let detail = match account.batch {
+            Some(batch) => format!("batch {batch}: {detail}"),
+            None => detail,
+        };
+        lines.push(format!("{:<42}  {label:<10}  {detail}", account.signer));
+    }
+
+    lines.push(String::new());
+    lines.push(
+        style("[p] pause/resume  [+] raise fee caps  [q] abort")
+            .dim()
+            .to_string(),
+    );
+
+    lines
+        .into_iter()
+        .map(|line| truncate_str(&line, width, "…").into_owned())
+        .collect()
+}
+
+/// Returns the status label and the detail of an event.
+fn describe(kind: &TxEventKind) -> (&'static str, String) {
+    match kind {
+        TxEventKind::Submitted { tx_hash } => ("submitted", tx_hash.to_string()),
+        TxEventKind::Pending {
+            tx_hash,
+            block_number,
+        } => ("pending", format!("{tx_hash} in block {block_number}")),
+        TxEventKind::Confirmed {
+            tx_hash,
+            block_number,
+            ..
+        } => (
+            "confirmed",
+            match block_number {
+                Some(block_number) => format!("{tx_hash} in block {block_number}"),
+                None => tx_hash.to_string(),
+            },
+        ),
+        TxEventKind::Reverted { tx_hash, .. } => ("reverted", tx_hash.to_string()),
+        TxEventKind::Retried { tx_hash } => ("retried", tx_hash.to_string()),
+        TxEventKind::Skipped { reason } => ("skipped", reason.clone()),
+        TxEventKind::Failed { class, error } => ("failed", format!("[{class}] {error}")),
+    }
+}
+
+/// Formats an amount of wei in a larger unit.
+fn units(amount: U256, unit: &str) -> String {
+    format_units(amount, unit).unwrap_or_else(|_| amount.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::executor::TxEvent;
+    use alloy::primitives::{Address, TxHash};
+    use console::strip_ansi_codes;
+
+    #[test]
+    fn test_render() {
+        let mut state = DashboardState::new(2);
+        state.base_fee = Some(12_000_000_000);
+        for signer in 1..=3 {
+            state.apply(&TxEvent {
+                signer: Address::with_last_byte(signer),
+                batch: None,
+                kind: TxEventKind::Submitted {
+                    tx_hash: TxHash::with_last_byte(signer),
+                },
+            });
+        }
+        let control = RunControl::new();
+        control.pause();
+        control.raise_fees(10);
+
+        let lines: Vec<_> = render(&state, &control, 120, HEADER_LINES + 4)
+            .iter()
+            .map(|line| strip_ansi_codes(line).into_owned())
+            .collect();
+
+        assert!(lines[0].contains("0/2 accounts done  PAUSED"));
+        assert!(lines[1].contains("pending 3"));
+        assert!(lines[2].contains("base fee 12.000000000 gwei"));
+        assert!(lines[2].contains("fee caps +10%"));
+        // the account list is cut to the height of the terminal
+        assert_eq!(lines.len(), HEADER_LINES + 4);
+        assert!(lines.iter().all(|line| line.chars().count() <= 120));
+    }
+}
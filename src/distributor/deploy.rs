@@ -0,0 +1,139 @@
+use crate::bindings::Distributor;
+use crate::network::rpc_client;
+use alloy::{
+    network::{EthereumWallet, TransactionBuilder, TxSigner},
+    primitives::{address, Address, Bytes, PrimitiveSignature, B256},
+    providers::{Provider, ProviderBuilder},
+    rpc::types::TransactionRequest,
+    transports::http::reqwest::Url,
+};
+use eyre::{eyre, Result};
+use tracing::{info, instrument};
+
+/// The deterministic deployment proxy, available at this address on most EVM chains.
+///
+/// It deploys `init_code` with `CREATE2` when called with `salt ++ init_code`.
+pub const CREATE2_DEPLOYER: Address = address!("4e59b44847b379578588920cA78FbF26c0B4956C");
+
+/// The salt of the canonical deterministic `Distributor` deployment.
+pub const DISTRIBUTOR_SALT: B256 = B256::ZERO;
+
+/// Deploys the embedded `Distributor` contract.
+///
+/// # Arguments
+///
+/// * `signer` - The signer of the deploying account.
+/// * `rpc_http` - The HTTP URL of the Ethereum RPC endpoint.
+///
+/// # Returns
+///
+/// * `Result<Address>` - The address of the deployed contract on success.
+#[instrument(skip_all, fields(signer = %signer.address()))]
+pub async fn deploy<S>(signer: S, rpc_http: Url) -> Result<Address>
+where
+    S: TxSigner<PrimitiveSignature> + Send + Sync + 'static,
+{
+    let provider = ProviderBuilder::new()
+        .with_recommended_fillers()
+        .wallet(EthereumWallet::new(signer))
+        .on_client(rpc_client(rpc_http));
+
+    let tx = TransactionRequest::default().with_deploy_code(Distributor::BYTECODE.clone());
+    let receipt = provider.send_transaction(tx).await?.get_receipt().await?;
+    if !receipt.status() {
+        return Err(eyre!(
+            "the deployment transaction {} reverted",
+            receipt.transaction_hash
+        ));
+    }
+
+    let contract_address = receipt
+        .contract_address
+        .ok_or_else(|| eyre!("the deployment receipt has no contract address"))?;
+    info!(%contract_address, tx_hash = %receipt.transaction_hash, "distributor deployed");
+
+    Ok(contract_address)
+}
+
+/// Computes the address of a deterministic `Distributor` deployment.
+///
+/// # Arguments
+///
+/// * `salt` - The `CREATE2` salt, [`DISTRIBUTOR_SALT`] for the canonical deployment.
+///
+/// # Returns
+///
+/// * `Address` - The address the contract is deployed at, the same on every chain.
+pub fn deterministic_address(salt: B256) -> Address {
+    CREATE2_DEPLOYER.create2_from_code(salt, &Distributor::BYTECODE)
+}
+
+/// Deploys the embedded `Distributor` contract through the [`CREATE2_DEPLOYER`], so that it
+/// has the same address on every chain.
+///
+/// Nothing is sent if the contract is already deployed.
+///
+/// # Arguments
+///
+/// * `signer` - The signer of the deploying account.
+/// * `rpc_http` - The HTTP URL of the Ethereum RPC endpoint.
+/// * `salt` - The `CREATE2` salt, [`DISTRIBUTOR_SALT`] for the canonical deployment.
+///
+/// # Returns
+///
+/// * `Result<Address>` - The address of the contract on success.
+#[instrument(skip(signer, rpc_http), fields(signer = %signer.address()))]
+pub async fn deploy_deterministic<S>(signer: S, rpc_http: Url, salt: B256) -> Result<Address>
+where
+    S: TxSigner<PrimitiveSignature> + Send + Sync + 'static,
+{
+    let provider = ProviderBuilder::new()
+        .with_recommended_fillers()
+        .wallet(EthereumWallet::new(signer))
+        .on_client(rpc_client(rpc_http));
+
+    let contract_address = deterministic_address(salt);
+    if !provider.get_code_at(contract_address).await?.is_empty() {
+        info!(%contract_address, "distributor already deployed");
+        return Ok(contract_address);
+    }
+    if provider.get_code_at(CREATE2_DEPLOYER).await?.is_empty() {
+        return Err(eyre!(
+            "the CREATE2 deployer {CREATE2_DEPLOYER} is not available on this chain"
+        ));
+    }
+
+    let input: Bytes = [salt.as_slice(), &Distributor::BYTECODE].concat().into();
+    let tx = TransactionRequest::default()
+        .with_to(CREATE2_DEPLOYER)
+        .with_input(input);
+    let receipt = provider.send_transaction(tx).await?.get_receipt().await?;
+    if !receipt.status() || provider.get_code_at(contract_address).await?.is_empty() {
+        return Err(eyre!(
+            "the deployment transaction {} did not deploy the contract",
+            receipt.transaction_hash
+        ));
+    }
+    info!(%contract_address, tx_hash = %receipt.transaction_hash, "distributor deployed");
+
+    Ok(contract_address)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deterministic_address() {
+        // changes to the embedded bytecode move the canonical address, which must then be
+        // recomputed here
+        assert_eq!(
+            deterministic_address(DISTRIBUTOR_SALT),
+            address!("7FAd3c4Ca68723Cb4e9156814a9AcF14d49Bb102")
+        );
+        assert_ne!(
+            deterministic_address(DISTRIBUTOR_SALT),
+            deterministic_address(B256::with_last_byte(1))
+        );
+    }
+}
@@ -1,12 +1,29 @@
-use crate::executor::execute;
+use crate::{
+    bindings::Distributor,
+    executor::{send_batch, CallRequest, ExecuteOptions},
+    network::http_provider,
+};
 use alloy::{
-    dyn_abi::DynSolValue,
     json_abi::JsonAbi,
-    primitives::{Address, TxHash, U256},
-    signers::local::PrivateKeySigner,
+    network::TxSigner,
+    primitives::{Address, PrimitiveSignature, TxHash, U256},
+    providers::Provider,
     transports::http::reqwest::Url,
 };
-use eyre::Result;
+use eyre::{eyre, Result};
+use tracing::{info, instrument};
+
+/// The human-readable ABI of the `Distributor` contract.
+const DISTRIBUTOR_ABI: &[&str] = &["function distributeEther((address,uint256)[] txns) payable"];
+
+/// Returns the JSON ABI of the `Distributor` contract.
+///
+/// # Returns
+///
+/// * `JsonAbi` - The ABI of the `distributeEther` function.
+pub fn distributor_abi() -> JsonAbi {
+    JsonAbi::parse(DISTRIBUTOR_ABI.iter().copied()).expect("valid distributor ABI")
+}
 
 /// Parameters for the `distribute` function.
 ///
@@ -14,59 +31,299 @@ use eyre::Result;
 ///
 /// * `receiver` - The address of the receiver.
 /// * `amount` - The amount to be distributed.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct DistributeParam {
     pub receiver: Address,
     pub amount: U256,
 }
 
-/// Distributes Ether to multiple receivers.
+impl From<&DistributeParam> for Distributor::Transaction {
+    fn from(param: &DistributeParam) -> Self {
+        Self {
+            recipient: param.receiver,
+            amount: param.amount,
+        }
+    }
+}
+
+/// Encodes a typed `distributeEther` call, sending the sum of the amounts along.
 ///
 /// # Arguments
 ///
-/// * `sender` - The private key signer of the sender.
+/// * `contract_address` - The address of the `Distributor` contract.
+/// * `params` - The receiver addresses and amounts.
+///
+/// # Returns
+///
+/// * `CallRequest` - The encoded call.
+pub fn distribute_call(contract_address: Address, params: &[DistributeParam]) -> CallRequest {
+    let call = Distributor::distributeEtherCall {
+        txns: params.iter().map(Into::into).collect(),
+    };
+    let value: U256 = params.iter().map(|param| param.amount).sum();
+
+    CallRequest::from_call(contract_address, &call, Some(value))
+}
+
+/// Distributes Ether to multiple receivers through the typed `Distributor` bindings.
+///
+/// No ABI is needed, the call is checked at compile time. The transaction reports on the event
+/// channel of `options`, if any, as batch `0` of the sender.
+///
+/// # Arguments
+///
+/// * `sender` - The signer of the sender.
 /// * `rpc_http` - The HTTP URL of the Ethereum RPC endpoint.
-/// * `abi` - The JSON ABI of the contract.
-/// * `contract_address` - The address of the contract.
-/// * `params` - A vector of `DistributeParam` containing receiver addresses and amounts.
+/// * `contract_address` - The address of the `Distributor` contract.
+/// * `params` - The receiver addresses and amounts.
+/// * `options` - The options to send the transaction with.
 ///
 /// # Returns
 ///
 /// * `Result<TxHash>` - The transaction hash on success.
-pub async fn distribute(
-    sender: PrivateKeySigner,
+#[instrument(
+    skip_all,
+    fields(contract = %contract_address, receivers = params.len())
+)]
+pub async fn distribute_ether<S>(
+    sender: S,
     rpc_http: Url,
-    abi: JsonAbi,
     contract_address: Address,
-    params: Vec<DistributeParam>,
-) -> Result<TxHash> {
-    let txns = DynSolValue::Array(
-        params
-            .iter()
-            .map(|r| {
-                DynSolValue::Tuple(vec![
-                    DynSolValue::from(r.receiver),
-                    DynSolValue::from(r.amount),
-                ])
-            })
-            .collect(),
+    params: &[DistributeParam],
+    options: &ExecuteOptions,
+) -> Result<TxHash>
+where
+    S: TxSigner<PrimitiveSignature> + Send + Sync + 'static,
+{
+    distribute_batch(sender, rpc_http, contract_address, params, options, 0).await
+}
+
+/// Distributes Ether to one batch of receivers, reporting its events under `batch`.
+pub(crate) async fn distribute_batch<S>(
+    sender: S,
+    rpc_http: Url,
+    contract_address: Address,
+    params: &[DistributeParam],
+    options: &ExecuteOptions,
+    batch: usize,
+) -> Result<TxHash>
+where
+    S: TxSigner<PrimitiveSignature> + Send + Sync + 'static,
+{
+    let call = distribute_call(contract_address, params);
+    let value = call.value;
+
+    let tx_hash = send_batch(sender, rpc_http, call, options, batch)
+        .await?
+        .tx_hash;
+    info!(%value, %tx_hash, batch, "ether distributed");
+
+    Ok(tx_hash)
+}
+
+/// Computes what each receiver lacks to hold at least `target`.
+///
+/// # Arguments
+///
+/// * `rpc_http` - The HTTP URL of the Ethereum RPC endpoint.
+/// * `receivers` - The addresses to top up.
+/// * `target` - The balance each receiver must hold.
+///
+/// # Returns
+///
+/// * `Result<Vec<DistributeParam>>` - The missing amounts, without the receivers already
+///   holding `target`, on success.
+#[instrument(level = "debug", skip(rpc_http, receivers), fields(receivers = receivers.len()))]
+pub async fn top_up_params(
+    rpc_http: Url,
+    receivers: &[Address],
+    target: U256,
+) -> Result<Vec<DistributeParam>> {
+    let provider = http_provider(rpc_http);
+
+    let mut params = Vec::with_capacity(receivers.len());
+    for &receiver in receivers {
+        let balance = provider.get_balance(receiver).await?;
+        if balance < target {
+            params.push(DistributeParam {
+                receiver,
+                amount: target - balance,
+            });
+        }
+    }
+
+    Ok(params)
+}
+
+/// Tops up receivers so that each holds at least `target`, in batches.
+///
+/// Receivers already holding `target` are skipped; nothing is sent if all of them do. Each
+/// batch reports on the event channel of `options`, if any, under the sender's address and its
+/// index, see [`TxEvent::batch`](crate::executor::TxEvent::batch).
+///
+/// # Arguments
+///
+/// * `sender` - The signer of the sender.
+/// * `rpc_http` - The HTTP URL of the Ethereum RPC endpoint.
+/// * `contract_address` - The address of the `Distributor` contract.
+/// * `receivers` - The addresses to top up.
+/// * `target` - The balance each receiver must hold.
+/// * `batch_size` - The maximum number of receivers per transaction.
+/// * `options` - The options to send the transactions with.
+///
+/// # Returns
+///
+/// * `Result<Vec<TxHash>>` - The hashes of the funding transactions on success.
+#[instrument(
+    skip(sender, rpc_http, receivers, options),
+    fields(receivers = receivers.len())
+)]
+pub async fn top_up<S>(
+    sender: S,
+    rpc_http: Url,
+    contract_address: Address,
+    receivers: &[Address],
+    target: U256,
+    batch_size: usize,
+    options: &ExecuteOptions,
+) -> Result<Vec<TxHash>>
+where
+    S: TxSigner<PrimitiveSignature> + Clone + Send + Sync + 'static,
+{
+    if batch_size == 0 {
+        return Err(eyre!("the batch size must be greater than zero"));
+    }
+
+    let params = top_up_params(rpc_http.clone(), receivers, target).await?;
+    info!(
+        missing = params.len(),
+        batches = params.len().div_ceil(batch_size),
+        "topping up receivers"
     );
 
-    let args = &[txns];
+    let mut tx_hashes = Vec::new();
+    for (index, batch) in params.chunks(batch_size).enumerate() {
+        let tx_hash = distribute_batch(
+            sender.clone(),
+            rpc_http.clone(),
+            contract_address,
+            batch,
+            options,
+            index,
+        )
+        .await?;
+        tx_hashes.push(tx_hash);
+    }
 
-    let value: U256 = params.iter().map(|param| param.amount).sum();
+    Ok(tx_hashes)
+}
 
-    let tx_hash = execute(
+/// Distributes Ether to multiple receivers.
+///
+/// The call is built from the typed `Distributor` bindings, see [`distribute_ether`]; `abi` is
+/// ignored and only kept so that existing callers still compile.
+///
+/// # Arguments
+///
+/// * `sender` - The signer of the sender.
+/// * `rpc_http` - The HTTP URL of the Ethereum RPC endpoint.
+/// * `abi` - Ignored, every `Distributor` shares the typed ABI.
+/// * `contract_address` - The address of the contract.
+/// * `params` - A vector of `DistributeParam` containing receiver addresses and amounts.
+///
+/// # Returns
+///
+/// * `Result<TxHash>` - The transaction hash on success.
+pub async fn distribute<S>(
+    sender: S,
+    rpc_http: Url,
+    _abi: JsonAbi,
+    contract_address: Address,
+    params: Vec<DistributeParam>,
+) -> Result<TxHash>
+where
+    S: TxSigner<PrimitiveSignature> + Send + Sync + 'static,
+{
+    distribute_with_options(
         sender,
         rpc_http,
-        abi,
         contract_address,
-        "distributeEther",
-        args,
-        Some(value),
+        params,
+        &ExecuteOptions::default(),
     )
-    .await?
-    .tx_hash;
+    .await
+}
 
-    Ok(tx_hash)
+/// Distributes Ether to multiple receivers with the given options.
+///
+/// The call is built from the typed `Distributor` bindings, see [`distribute_ether`].
+///
+/// # Arguments
+///
+/// * `sender` - The signer of the sender.
+/// * `rpc_http` - The HTTP URL of the Ethereum RPC endpoint.
+/// * `contract_address` - The address of the contract.
+/// * `params` - A vector of `DistributeParam` containing receiver addresses and amounts.
+/// * `options` - The options to send the transaction with.
+///
+/// # Returns
+///
+/// * `Result<TxHash>` - The transaction hash on success.
+pub async fn distribute_with_options<S>(
+    sender: S,
+    rpc_http: Url,
+    contract_address: Address,
+    params: Vec<DistributeParam>,
+    options: &ExecuteOptions,
+) -> Result<TxHash>
+where
+    S: TxSigner<PrimitiveSignature> + Send + Sync + 'static,
+{
+    distribute_ether(sender, rpc_http, contract_address, &params, options).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy::{dyn_abi::DynSolValue, primitives::keccak256};
+
+    #[test]
+    fn test_distributor_abi() {
+        let abi = distributor_abi();
+        let function = &abi.function("distributeEther").unwrap()[0];
+
+        assert_eq!(
+            function.selector().as_slice(),
+            &keccak256("distributeEther((address,uint256)[])")[..4]
+        );
+    }
+
+    #[test]
+    fn test_distribute_call_matches_abi() {
+        let contract_address = Address::repeat_byte(1);
+        let params: Vec<DistributeParam> = (2..5)
+            .map(|i| DistributeParam {
+                receiver: Address::repeat_byte(i),
+                amount: U256::from(i),
+            })
+            .collect();
+        let txns = DynSolValue::Array(
+            params
+                .iter()
+                .map(|p| DynSolValue::Tuple(vec![p.receiver.into(), p.amount.into()]))
+                .collect(),
+        );
+
+        let typed = distribute_call(contract_address, &params);
+        let dynamic = CallRequest::from_abi(
+            &distributor_abi(),
+            contract_address,
+            "distributeEther",
+            &[txns],
+            Some(U256::from(9)),
+        )
+        .unwrap();
+
+        assert_eq!(typed, dynamic);
+    }
 }
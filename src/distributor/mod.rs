@@ -1,2 +1,11 @@
 mod distribute;
-pub use distribute::{distribute, DistributeParam};
+pub(crate) use distribute::distribute_batch;
+pub use distribute::{
+    distribute, distribute_call, distribute_ether, distribute_with_options, distributor_abi,
+    top_up, top_up_params, DistributeParam,
+};
+
+mod deploy;
+pub use deploy::{
+    deploy, deploy_deterministic, deterministic_address, CREATE2_DEPLOYER, DISTRIBUTOR_SALT,
+};
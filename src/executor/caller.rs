@@ -1,12 +1,14 @@
+use crate::network::rpc_client;
 use alloy::{
     contract::{ContractInstance, Interface},
     dyn_abi::DynSolValue,
     json_abi::JsonAbi,
-    primitives::Address,
+    primitives::{Address, U256},
     providers::ProviderBuilder,
     transports::http::reqwest::Url,
 };
-use eyre::Result;
+use eyre::{eyre, Result, WrapErr};
+use tracing::instrument;
 
 /// Calls a function on an Ethereum smart contract.
 ///
@@ -21,6 +23,7 @@ use eyre::Result;
 /// # Returns
 ///
 /// * `Result<Vec<DynSolValue>>` - The result of the function call on success.
+#[instrument(level = "debug", skip(rpc_http, abi, args), err)]
 pub async fn call(
     rpc_http: Url,
     abi: JsonAbi,
@@ -30,7 +33,7 @@ pub async fn call(
 ) -> Result<Vec<DynSolValue>> {
     let provider = ProviderBuilder::new()
         .with_recommended_fillers()
-        .on_http(rpc_http);
+        .on_client(rpc_client(rpc_http));
 
     let contract = ContractInstance::new(contract_address, provider.clone(), Interface::new(abi));
 
@@ -38,3 +41,68 @@ pub async fn call(
 
     Ok(value)
 }
+
+/// Reads a view function without arguments returning a single value of type `ty`.
+async fn read_view(
+    rpc_http: Url,
+    contract_address: Address,
+    function_name: &str,
+    ty: &str,
+) -> Result<DynSolValue> {
+    let abi = JsonAbi::parse([format!("function {function_name}() view returns ({ty})").as_str()])
+        .wrap_err_with(|| format!("invalid view function name `{function_name}`"))?;
+
+    call(rpc_http, abi, contract_address, function_name, &[])
+        .await?
+        .into_iter()
+        .next()
+        .ok_or_else(|| eyre!("`{function_name}()` did not return a {ty}"))
+}
+
+/// Reads a `uint256` view function without arguments, such as `mintPrice()` or `totalSupply()`.
+///
+/// No ABI is needed, the function is assumed to be `function <name>() view returns (uint256)`.
+///
+/// # Arguments
+///
+/// * `rpc_http` - The HTTP URL of the Ethereum RPC endpoint.
+/// * `contract_address` - The address of the contract.
+/// * `function_name` - The name of the view function.
+///
+/// # Returns
+///
+/// * `Result<U256>` - The returned value on success.
+pub async fn read_uint(
+    rpc_http: Url,
+    contract_address: Address,
+    function_name: &str,
+) -> Result<U256> {
+    match read_view(rpc_http, contract_address, function_name, "uint256").await? {
+        DynSolValue::Uint(value, _) => Ok(value),
+        _ => Err(eyre!("`{function_name}()` did not return a uint256")),
+    }
+}
+
+/// Reads a `bool` view function without arguments, such as `mintEnabled()`.
+///
+/// No ABI is needed, the function is assumed to be `function <name>() view returns (bool)`.
+///
+/// # Arguments
+///
+/// * `rpc_http` - The HTTP URL of the Ethereum RPC endpoint.
+/// * `contract_address` - The address of the contract.
+/// * `function_name` - The name of the view function.
+///
+/// # Returns
+///
+/// * `Result<bool>` - The returned value on success.
+pub async fn read_bool(
+    rpc_http: Url,
+    contract_address: Address,
+    function_name: &str,
+) -> Result<bool> {
+    match read_view(rpc_http, contract_address, function_name, "bool").await? {
+        DynSolValue::Bool(value) => Ok(value),
+        _ => Err(eyre!("`{function_name}()` did not return a bool")),
+    }
+}
@@ -0,0 +1,139 @@
+use eyre::{eyre, Result};
+use std::sync::{
+    atomic::{AtomicU64, Ordering},
+    Arc,
+};
+use tokio::sync::watch;
+
+/// Steers a running batch of transactions from the outside, e.g. from a dashboard.
+///
+/// Clones share the same state. Pausing holds back transactions that are not sent yet, those in
+/// flight are followed as usual; aborting skips every transaction not sent yet.
+#[derive(Debug, Clone)]
+pub struct RunControl {
+    flags: Arc<watch::Sender<Flags>>,
+    fee_bump_percent: Arc<AtomicU64>,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct Flags {
+    paused: bool,
+    aborted: bool,
+}
+
+impl Default for RunControl {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RunControl {
+    /// Creates a new `RunControl` for a run that is neither paused nor aborted.
+    pub fn new() -> Self {
+        Self {
+            flags: Arc::new(watch::channel(Flags::default()).0),
+            fee_bump_percent: Arc::default(),
+        }
+    }
+
+    /// Holds back the transactions not sent yet.
+    pub fn pause(&self) {
+        self.flags.send_modify(|flags| flags.paused = true);
+    }
+
+    /// Lets held back transactions go.
+    pub fn resume(&self) {
+        self.flags.send_modify(|flags| flags.paused = false);
+    }
+
+    /// Pauses a running run or resumes a paused one, returning `true` if it is now paused.
+    pub fn toggle_pause(&self) -> bool {
+        let mut paused = false;
+        self.flags.send_modify(|flags| {
+            flags.paused = !flags.paused;
+            paused = flags.paused;
+        });
+
+        paused
+    }
+
+    /// Returns `true` while the run is paused.
+    pub fn is_paused(&self) -> bool {
+        self.flags.borrow().paused
+    }
+
+    /// Skips every transaction not sent yet.
+    pub fn abort(&self) {
+        self.flags.send_modify(|flags| flags.aborted = true);
+    }
+
+    /// Returns `true` once the run was aborted.
+    pub fn is_aborted(&self) -> bool {
+        self.flags.borrow().aborted
+    }
+
+    /// Raises the fee caps of the transactions not signed yet by `percent` more, returning the
+    /// total raise in percent.
+    pub fn raise_fees(&self, percent: u64) -> u64 {
+        self.fee_bump_percent.fetch_add(percent, Ordering::Relaxed) + percent
+    }
+
+    /// Returns how much the fee caps are raised, in percent.
+    pub fn fee_bump_percent(&self) -> u64 {
+        self.fee_bump_percent.load(Ordering::Relaxed)
+    }
+
+    /// Applies the fee raise to a fee.
+    pub(crate) fn bump(&self, fee: u128) -> u128 {
+        let percent = u128::from(self.fee_bump_percent());
+        fee.saturating_mul(100 + percent) / 100
+    }
+
+    /// Waits while the run is paused.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Ok once the transaction may be sent, or an error if the run was aborted.
+    pub(crate) async fn checkpoint(&self) -> Result<()> {
+        let mut flags = self.flags.subscribe();
+        let flags = flags
+            .wait_for(|flags| !flags.paused || flags.aborted)
+            .await?;
+        if flags.aborted {
+            return Err(eyre!("the run was aborted"));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn test_run_control() {
+        let control = RunControl::new();
+        control.checkpoint().await.unwrap();
+
+        assert!(control.toggle_pause());
+        let paused = tokio::time::timeout(Duration::from_millis(50), control.checkpoint()).await;
+        assert!(paused.is_err());
+
+        let waiting = tokio::spawn({
+            let control = control.clone();
+            async move { control.checkpoint().await }
+        });
+        control.resume();
+        waiting.await.unwrap().unwrap();
+
+        assert_eq!(control.raise_fees(10), 10);
+        assert_eq!(control.raise_fees(10), 20);
+        assert_eq!(control.bump(1_000), 1_200);
+
+        control.pause();
+        control.abort();
+        assert!(control.checkpoint().await.is_err());
+    }
+}
@@ -0,0 +1,80 @@
+use alloy::transports::{RpcError, TransportErrorKind};
+use eyre::Report;
+use std::fmt;
+
+/// A coarse classification of why a transaction failed, for logs and metrics.
+///
+/// # Variants
+///
+/// * `Revert` - The call reverted, either when estimating its gas or once included.
+/// * `Rpc` - The node rejected the request, e.g. a nonce too low or an underpriced replacement.
+/// * `Transport` - The node could not be reached or answered with garbage.
+/// * `Other` - Anything else, e.g. a signing or encoding error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ErrorClass {
+    Revert,
+    Rpc,
+    Transport,
+    Other,
+}
+
+impl ErrorClass {
+    /// Classifies an error by walking its chain of causes.
+    pub fn of(error: &Report) -> Self {
+        if error
+            .chain()
+            .any(|cause| cause.to_string().contains("revert"))
+        {
+            return Self::Revert;
+        }
+
+        let rpc_error = error
+            .chain()
+            .find_map(|cause| cause.downcast_ref::<RpcError<TransportErrorKind>>());
+        match rpc_error {
+            Some(RpcError::ErrorResp(_)) => Self::Rpc,
+            Some(RpcError::Transport(_) | RpcError::DeserError { .. }) => Self::Transport,
+            Some(_) | None => Self::Other,
+        }
+    }
+
+    /// Returns the name of the class, as used in logs.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Revert => "revert",
+            Self::Rpc => "rpc",
+            Self::Transport => "transport",
+            Self::Other => "other",
+        }
+    }
+}
+
+impl fmt::Display for ErrorClass {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use eyre::{eyre, WrapErr};
+
+    #[test]
+    fn test_error_class() {
+        let revert = Err::<(), _>(eyre!("execution reverted: Total supply exceeded"))
+            .wrap_err("failed to send the transaction")
+            .unwrap_err();
+        assert_eq!(ErrorClass::of(&revert), ErrorClass::Revert);
+
+        let payload = r#"{"code":-32000,"message":"nonce too low"}"#;
+        let rpc: RpcError<TransportErrorKind> =
+            RpcError::ErrorResp(serde_json::from_str(payload).unwrap());
+        assert_eq!(ErrorClass::of(&rpc.into()), ErrorClass::Rpc);
+
+        let transport = TransportErrorKind::custom_str("connection refused");
+        assert_eq!(ErrorClass::of(&transport.into()), ErrorClass::Transport);
+
+        assert_eq!(ErrorClass::of(&eyre!("invalid key")), ErrorClass::Other);
+    }
+}
@@ -0,0 +1,228 @@
+use super::ErrorClass;
+use crate::account::Progress;
+use alloy::primitives::{Address, TxHash, U256};
+use eyre::Report;
+use tokio::sync::mpsc::{self, UnboundedReceiver, UnboundedSender};
+
+/// The sending half of an event channel, set on [`ExecuteOptions::events`].
+///
+/// [`ExecuteOptions::events`]: super::ExecuteOptions::events
+pub type EventSender = UnboundedSender<TxEvent>;
+
+/// Something that happened to the transaction of an account or of a batch.
+///
+/// The events of a run come from two kinds of sources: an account sending its own transaction,
+/// with no `batch`, and the batches sending Ether to several accounts at once, e.g. funding
+/// chunks, which share the address of their sender and are told apart by `batch`.
+///
+/// # Fields
+///
+/// * `signer` - The account sending the transaction.
+/// * `batch` - The index of the batch the transaction sends, if it acts for several accounts.
+/// * `kind` - What happened.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TxEvent {
+    pub signer: Address,
+    pub batch: Option<usize>,
+    pub kind: TxEventKind,
+}
+
+impl TxEvent {
+    /// Returns `true` if the event is about the own transaction of an account, not a batch.
+    pub fn is_account(&self) -> bool {
+        self.batch.is_none()
+    }
+}
+
+/// What happened to a transaction.
+///
+/// Every source of events, i.e. every `(signer, batch)` pair of [`TxEvent`], ends with exactly
+/// one of `Confirmed`, `Reverted`, `Skipped` or `Failed`, see [`TxEventKind::is_final`].
+///
+/// # Variants
+///
+/// * `Submitted` - The transaction was signed and sent.
+/// * `Pending` - The transaction was included and waits for more confirmations.
+/// * `Confirmed` - The transaction was included, and is final if confirmations were required.
+/// * `Reverted` - The transaction was included but reverted.
+/// * `Retried` - The transaction was dropped by a reorg and sent again.
+/// * `Skipped` - No transaction was sent, e.g. once the supply cap was reached.
+/// * `Failed` - The transaction could not be sent or included.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TxEventKind {
+    Submitted {
+        tx_hash: TxHash,
+    },
+    Pending {
+        tx_hash: TxHash,
+        block_number: u64,
+    },
+    Confirmed {
+        tx_hash: TxHash,
+        block_number: Option<u64>,
+        gas_cost: U256,
+    },
+    Reverted {
+        tx_hash: TxHash,
+        gas_cost: U256,
+    },
+    Retried {
+        tx_hash: TxHash,
+    },
+    Skipped {
+        reason: String,
+    },
+    Failed {
+        class: ErrorClass,
+        error: String,
+    },
+}
+
+impl TxEventKind {
+    /// Creates the `Failed` event of an error.
+    pub(crate) fn failed(error: &Report) -> Self {
+        Self::Failed {
+            class: ErrorClass::of(error),
+            error: error.to_string(),
+        }
+    }
+
+    /// Returns `true` for the last event of a source.
+    pub fn is_final(&self) -> bool {
+        matches!(
+            self,
+            Self::Confirmed { .. }
+                | Self::Reverted { .. }
+                | Self::Skipped { .. }
+                | Self::Failed { .. }
+        )
+    }
+}
+
+/// Creates an event channel; the receiver yields events as they happen.
+///
+/// The channel is unbounded so that a slow consumer never holds transactions back.
+pub fn event_channel() -> (EventSender, UnboundedReceiver<TxEvent>) {
+    mpsc::unbounded_channel()
+}
+
+/// Drives a [`Progress`] from an event channel, one step per account done.
+///
+/// The events of batches are collected but do not count as accounts.
+///
+/// # Arguments
+///
+/// * `events` - The receiving half of the channel.
+/// * `accounts` - The number of accounts of the run.
+/// * `progress` - The progress to report to, e.g. a terminal bar.
+///
+/// # Returns
+///
+/// * `Vec<TxEvent>` - Every event received, once all senders are dropped.
+pub async fn track_progress(
+    mut events: UnboundedReceiver<TxEvent>,
+    accounts: usize,
+    progress: &dyn Progress,
+) -> Vec<TxEvent> {
+    progress.start(accounts as u64);
+
+    let mut received = Vec::new();
+    while let Some(event) = events.recv().await {
+        if event.is_account() && event.kind.is_final() {
+            progress.inc(1);
+        }
+        received.push(event);
+    }
+    progress.finish();
+
+    received
+}
+
+/// Emits the events of a single source, if a channel is set.
+#[derive(Debug, Clone)]
+pub(crate) struct EventSink {
+    sender: Option<EventSender>,
+    signer: Address,
+    batch: Option<usize>,
+}
+
+impl EventSink {
+    pub(crate) fn new(sender: Option<&EventSender>, signer: Address) -> Self {
+        Self {
+            sender: sender.cloned(),
+            signer,
+            batch: None,
+        }
+    }
+
+    /// Emits the events of a batch of `signer` rather than of its own transaction.
+    pub(crate) fn with_batch(mut self, batch: Option<usize>) -> Self {
+        self.batch = batch;
+        self
+    }
+
+    pub(crate) fn emit(&self, kind: TxEventKind) {
+        if let Some(sender) = &self.sender {
+            // a consumer that went away does not stop the run
+            let _ = sender.send(TxEvent {
+                signer: self.signer,
+                batch: self.batch,
+                kind,
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicU64, Ordering};
+
+    #[derive(Default)]
+    struct Steps(AtomicU64);
+
+    impl Progress for Steps {
+        fn start(&self, _len: u64) {}
+
+        fn inc(&self, delta: u64) {
+            self.0.fetch_add(delta, Ordering::Relaxed);
+        }
+
+        fn finish(&self) {}
+    }
+
+    #[tokio::test]
+    async fn test_track_progress() {
+        let (sender, receiver) = event_channel();
+        let tx_hash = TxHash::with_last_byte(1);
+
+        let events = EventSink::new(Some(&sender), Address::with_last_byte(1));
+        events.emit(TxEventKind::Submitted { tx_hash });
+        events.emit(TxEventKind::Confirmed {
+            tx_hash,
+            block_number: Some(1),
+            gas_cost: U256::ZERO,
+        });
+        EventSink::new(Some(&sender), Address::with_last_byte(2)).emit(TxEventKind::Skipped {
+            reason: "the supply cap was reached".to_owned(),
+        });
+        // a funding batch of the first account is not an account
+        EventSink::new(Some(&sender), Address::with_last_byte(1))
+            .with_batch(Some(0))
+            .emit(TxEventKind::Confirmed {
+                tx_hash,
+                block_number: Some(1),
+                gas_cost: U256::ZERO,
+            });
+        // without a channel, events go nowhere
+        EventSink::new(None, Address::ZERO).emit(TxEventKind::Submitted { tx_hash });
+        drop((sender, events));
+
+        let steps = Steps::default();
+        let received = track_progress(receiver, 2, &steps).await;
+
+        assert_eq!(received.len(), 4);
+        assert_eq!(received[3].batch, Some(0));
+        assert_eq!(steps.0.load(Ordering::Relaxed), 2);
+    }
+}
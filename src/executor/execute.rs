@@ -1,14 +1,28 @@
+use super::{
+    finality::{await_finality, TxStatus},
+    CallRequest, ErrorClass, EventSink, ExecuteOptions, TxEventKind,
+};
+use crate::network::{rpc_client, FeeStrategy};
 use alloy::{
-    contract::{ContractInstance, Interface},
+    consensus::Transaction,
     dyn_abi::DynSolValue,
+    eips::eip2718::Encodable2718,
     json_abi::JsonAbi,
-    network::{Ethereum, EthereumWallet},
-    primitives::{Address, TxHash, U256},
-    providers::ProviderBuilder,
-    signers::local::PrivateKeySigner,
-    transports::http::{reqwest::Url, Client, Http},
+    network::{EthereumWallet, TransactionBuilder, TxSigner},
+    primitives::{Address, Bytes, PrimitiveSignature, TxHash, U256},
+    providers::{Provider, ProviderBuilder},
+    rpc::types::TransactionRequest,
+    transports::http::reqwest::Url,
 };
-use eyre::Result;
+use eyre::{eyre, Result};
+use std::{sync::Arc, time::Instant};
+use tracing::{debug, field::display, info, instrument, warn, Span};
+
+/// A type-erased transaction signer.
+///
+/// Useful to mix signers of different kinds, e.g. local keys and a remote signing service, in a
+/// single `mint_loop` run.
+pub type DynSigner = Arc<dyn TxSigner<PrimitiveSignature> + Send + Sync>;
 
 /// Represents the result of a contract execution.
 ///
@@ -16,10 +30,20 @@ use eyre::Result;
 ///
 /// * `caller` - The address of the caller.
 /// * `tx_hash` - The transaction hash of the executed transaction.
+/// * `explorer_url` - The block explorer link of the transaction, if the network has one.
+/// * `transitions` - The statuses of the transaction from its first inclusion on; it ends
+///   with [`TxStatus::Confirmed`] when a [`Finality`](super::Finality) was required.
+/// * `gas_cost` - What the transaction paid for gas, in wei, from its receipt.
+/// * `succeeded` - Whether its receipt has a success status; `false` if it was included but
+///   reverted.
 #[derive(Debug)]
 pub struct Execution {
     pub caller: Address,
     pub tx_hash: TxHash,
+    pub explorer_url: Option<String>,
+    pub transitions: Vec<TxStatus>,
+    pub gas_cost: U256,
+    pub succeeded: bool,
 }
 
 impl Execution {
@@ -29,12 +53,40 @@ impl Execution {
     ///
     /// * `caller` - The address of the caller.
     /// * `tx_hash` - The transaction hash of the executed transaction.
+    /// * `options` - The options the transaction was sent with.
+    /// * `transitions` - The statuses of the transaction.
+    /// * `gas_cost` - What the transaction paid for gas, in wei.
+    /// * `succeeded` - Whether its receipt has a success status.
     ///
     /// # Returns
     ///
     /// * `Self` - A new `Execution` instance.
-    fn new(caller: Address, tx_hash: TxHash) -> Self {
-        Self { caller, tx_hash }
+    fn new(
+        caller: Address,
+        tx_hash: TxHash,
+        options: &ExecuteOptions,
+        transitions: Vec<TxStatus>,
+        gas_cost: U256,
+        succeeded: bool,
+    ) -> Self {
+        let explorer_url = options
+            .network
+            .as_ref()
+            .and_then(|network| network.tx_url(tx_hash));
+
+        Self {
+            caller,
+            tx_hash,
+            explorer_url,
+            transitions,
+            gas_cost,
+            succeeded,
+        }
+    }
+
+    /// Returns the latest status of the transaction.
+    pub fn status(&self) -> Option<&TxStatus> {
+        self.transitions.last()
     }
 }
 
@@ -42,7 +94,7 @@ impl Execution {
 ///
 /// # Arguments
 ///
-/// * `account` - The private key signer of the account executing the transaction.
+/// * `account` - The signer of the account executing the transaction.
 /// * `rpc_http` - The HTTP URL of the Ethereum RPC endpoint.
 /// * `abi` - The JSON ABI of the contract.
 /// * `contract_address` - The address of the contract.
@@ -53,32 +105,292 @@ impl Execution {
 /// # Returns
 ///
 /// * `Result<Execution>` - The result of the contract execution, containing the caller's address and the transaction hash.
-pub async fn execute(
-    account: PrivateKeySigner,
+#[instrument(skip(account, rpc_http, abi, args, value), fields(signer = %account.address()))]
+pub async fn execute<S>(
+    account: S,
     rpc_http: Url,
     abi: JsonAbi,
     contract_address: Address,
     function_name: &str,
     args: &[DynSolValue],
     value: Option<U256>,
-) -> Result<Execution> {
+) -> Result<Execution>
+where
+    S: TxSigner<PrimitiveSignature> + Send + Sync + 'static,
+{
+    let call = CallRequest::from_abi(&abi, contract_address, function_name, args, value)?;
+
+    send_call(account, rpc_http, call, &ExecuteOptions::default()).await
+}
+
+/// Sends an encoded contract call and waits for its inclusion.
+///
+/// When a network profile is configured, the chain id of the RPC endpoint is verified before
+/// the transaction is signed.
+///
+/// # Arguments
+///
+/// * `account` - The signer of the account executing the transaction.
+/// * `rpc_http` - The HTTP URL of the Ethereum RPC endpoint.
+/// * `call` - The encoded contract call.
+/// * `options` - The options to send the transaction with.
+///
+/// # Returns
+///
+/// * `Result<Execution>` - The result of the contract execution, containing the caller's address and the transaction hash.
+#[instrument(skip_all, fields(signer = %account.address(), to = %call.to))]
+pub async fn send_call<S>(
+    account: S,
+    rpc_http: Url,
+    call: CallRequest,
+    options: &ExecuteOptions,
+) -> Result<Execution>
+where
+    S: TxSigner<PrimitiveSignature> + Send + Sync + 'static,
+{
+    if let Some(network) = &options.network {
+        network.verify(rpc_http.clone()).await?;
+    }
+
+    submit(account, rpc_http, call, options, None).await
+}
+
+/// Sends an encoded contract call acting for several accounts, e.g. a funding batch.
+///
+/// Works like [`send_call`], but the events carry `batch` so that they are not taken for the
+/// sender's own transaction.
+#[instrument(skip_all, fields(signer = %account.address(), to = %call.to, batch = batch))]
+pub(crate) async fn send_batch<S>(
+    account: S,
+    rpc_http: Url,
+    call: CallRequest,
+    options: &ExecuteOptions,
+    batch: usize,
+) -> Result<Execution>
+where
+    S: TxSigner<PrimitiveSignature> + Send + Sync + 'static,
+{
+    if let Some(network) = &options.network {
+        network.verify(rpc_http.clone()).await?;
+    }
+
+    submit(account, rpc_http, call, options, Some(batch)).await
+}
+
+/// Sends an encoded contract call without verifying the network.
+///
+/// Callers sending many transactions verify the network once up front and use this afterwards.
+/// Every transaction is logged in a `submit` span, with its failure and [`ErrorClass`] if any,
+/// and reported on the event channel of `options`, under `batch` if it acts for several accounts.
+#[instrument(
+    skip_all,
+    fields(signer = %account.address(), to = %call.to, nonce, tx_hash)
+)]
+pub(crate) async fn submit<S>(
+    account: S,
+    rpc_http: Url,
+    call: CallRequest,
+    options: &ExecuteOptions,
+    batch: Option<usize>,
+) -> Result<Execution>
+where
+    S: TxSigner<PrimitiveSignature> + Send + Sync + 'static,
+{
+    let started = Instant::now();
+    let events = EventSink::new(options.events.as_ref(), account.address()).with_batch(batch);
+    let mut sent = false;
+    let execution = sign_and_send(
+        account, rpc_http, call, options, &events, started, &mut sent,
+    )
+    .await;
+    if let Err(error) = &execution {
+        events.emit(TxEventKind::failed(error));
+        // transactions never accepted, e.g. aborted, gated or rejected by the node, were never counted
+        #[cfg(feature = "metrics")]
+        if sent {
+            crate::metrics::Metrics::global()
+                .transaction_failed(ErrorClass::of(error) == ErrorClass::Revert);
+        }
+        warn!(
+            error_class = %ErrorClass::of(error),
+            %error,
+            latency_ms = started.elapsed().as_millis() as u64,
+            "transaction failed"
+        );
+    }
+
+    execution
+}
+
+/// Signs and sends a transaction, then follows it until it is included or final.
+///
+/// `sent` is set once the node or the relay accepted the transaction.
+async fn sign_and_send<S>(
+    account: S,
+    rpc_http: Url,
+    call: CallRequest,
+    options: &ExecuteOptions,
+    events: &EventSink,
+    started: Instant,
+    sent: &mut bool,
+) -> Result<Execution>
+where
+    S: TxSigner<PrimitiveSignature> + Send + Sync + 'static,
+{
+    if let Some(control) = &options.control {
+        control.checkpoint().await?;
+    }
+    if let Some(gate) = &options.base_fee_gate {
+        gate.wait(rpc_http.clone()).await?;
+    }
+
     let caller = account.address();
     let wallet = EthereumWallet::new(account);
     let provider = ProviderBuilder::new()
         .with_recommended_fillers()
         .wallet(wallet)
-        .on_http(rpc_http);
+        .on_client(rpc_client(rpc_http.clone()));
+
+    let mut tx = TransactionRequest::default()
+        .with_to(call.to)
+        .with_input(call.input)
+        .with_value(call.value);
+
+    // fee caps raised from the outside are applied on top of the strategy
+    let control = options
+        .control
+        .as_ref()
+        .filter(|control| control.fee_bump_percent() > 0);
+    let bump = |fee: u128| control.map_or(fee, |control| control.bump(fee));
+    match options.fee_strategy() {
+        FeeStrategy::Eip1559 if control.is_some() => {
+            let fees = provider.estimate_eip1559_fees(None).await?;
+            tx.set_max_fee_per_gas(bump(fees.max_fee_per_gas));
+            tx.set_max_priority_fee_per_gas(bump(fees.max_priority_fee_per_gas));
+        }
+        FeeStrategy::Eip1559 => {}
+        FeeStrategy::Legacy => tx.set_gas_price(bump(provider.get_gas_price().await?)),
+        FeeStrategy::Fixed {
+            max_fee_per_gas,
+            max_priority_fee_per_gas,
+        } => {
+            tx.set_max_fee_per_gas(bump(max_fee_per_gas));
+            tx.set_max_priority_fee_per_gas(bump(max_priority_fee_per_gas));
+        }
+    }
 
-    let contract: ContractInstance<Http<Client>, _, Ethereum> =
-        ContractInstance::new(contract_address, provider.clone(), Interface::new(abi));
+    // the fillers sign the transaction, so it can be sent again after a reorg
+    let filled = provider.fill(tx).await?;
+    let envelope = filled
+        .as_envelope()
+        .ok_or_else(|| eyre!("the transaction of {caller} was not signed"))?;
+    let (raw, tx_hash): (Bytes, TxHash) = (envelope.encoded_2718().into(), *envelope.tx_hash());
+    Span::current()
+        .record("nonce", envelope.nonce())
+        .record("tx_hash", display(tx_hash));
+    debug!(
+        gas_limit = envelope.gas_limit(),
+        max_fee_per_gas = envelope.max_fee_per_gas(),
+        "transaction signed"
+    );
 
-    let tx_hash = contract
-        .function(function_name, args)?
-        .value(value.unwrap_or_default())
-        .send()
-        .await?
-        .watch()
+    // counted as sent only once the node or the relay accepted the transaction
+    #[cfg(feature = "metrics")]
+    let in_flight;
+    let receipt = match &options.relay {
+        Some(relay) => {
+            events.emit(TxEventKind::Submitted { tx_hash });
+            relay.submit(rpc_http.clone(), &raw, tx_hash).await?;
+            *sent = true;
+            #[cfg(feature = "metrics")]
+            {
+                in_flight = crate::metrics::Metrics::global().transaction_sent();
+            }
+            provider
+                .get_transaction_receipt(tx_hash)
+                .await?
+                .ok_or_else(|| eyre!("transaction {tx_hash} has no receipt"))?
+        }
+        None => {
+            let pending = provider.send_raw_transaction(&raw).await?;
+            *sent = true;
+            #[cfg(feature = "metrics")]
+            {
+                in_flight = crate::metrics::Metrics::global().transaction_sent();
+            }
+            events.emit(TxEventKind::Submitted { tx_hash });
+            pending.get_receipt().await?
+        }
+    };
+
+    info!(
+        block_number = receipt.block_number,
+        gas_used = receipt.gas_used,
+        effective_gas_price = receipt.effective_gas_price,
+        status = receipt.status(),
+        latency_ms = started.elapsed().as_millis() as u64,
+        "transaction included"
+    );
+
+    let mut transitions = Vec::new();
+    if let (Some(block_number), Some(block_hash)) = (receipt.block_number, receipt.block_hash) {
+        transitions.push(TxStatus::Included {
+            block_number,
+            block_hash,
+        });
+    }
+    if let Some(finality) = &options.finality {
+        if let Some(block_number) = receipt.block_number {
+            events.emit(TxEventKind::Pending {
+                tx_hash,
+                block_number,
+            });
+        }
+        await_finality(
+            rpc_http,
+            finality,
+            &raw,
+            tx_hash,
+            options.relay.as_ref(),
+            &mut transitions,
+            events,
+        )
         .await?;
+        debug!(?transitions, "transaction final");
+    }
+
+    let gas_cost = U256::from(receipt.gas_used) * U256::from(receipt.effective_gas_price);
+    #[cfg(feature = "metrics")]
+    {
+        let metrics = crate::metrics::Metrics::global();
+        let (gas_cost, latency) = (gas_cost.saturating_to(), started.elapsed());
+        match receipt.status() {
+            true => metrics.transaction_confirmed(gas_cost, latency),
+            false => metrics.transaction_reverted(gas_cost, latency),
+        }
+        drop(in_flight);
+    }
+    let block_number = transitions.iter().rev().find_map(|status| match status {
+        TxStatus::Included { block_number, .. } | TxStatus::Confirmed { block_number, .. } => {
+            Some(*block_number)
+        }
+        _ => None,
+    });
+    events.emit(match receipt.status() {
+        true => TxEventKind::Confirmed {
+            tx_hash,
+            block_number,
+            gas_cost,
+        },
+        false => TxEventKind::Reverted { tx_hash, gas_cost },
+    });
 
-    Ok(Execution::new(caller, tx_hash))
+    Ok(Execution::new(
+        caller,
+        tx_hash,
+        options,
+        transitions,
+        gas_cost,
+        receipt.status(),
+    ))
 }
@@ -0,0 +1,338 @@
+use super::{EventSink, TxEventKind};
+use crate::network::{http_provider, HttpTransport};
+use crate::relay::BundleRelay;
+use alloy::{
+    primitives::{BlockHash, Bytes, TxHash},
+    providers::{Provider, RootProvider},
+    rpc::types::BlockTransactionsKind,
+    transports::http::reqwest::Url,
+};
+use eyre::{eyre, Report, Result};
+use std::time::Duration;
+use tracing::debug;
+
+/// The default interval between two polls of the chain while waiting for confirmations.
+pub const DEFAULT_FINALITY_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// The default number of blocks a transaction dropped by a reorg has to be included again.
+pub const DEFAULT_REINCLUSION_BLOCKS: u64 = 25;
+
+/// Rejections, lowercased, meaning the node or relay already has the transaction or a later
+/// one of its sender, so sending it again is not needed.
+const ALREADY_SENT_ERRORS: &[&str] = &[
+    "already known",
+    "known transaction",
+    "already imported",
+    "alreadyknown",
+    "nonce too low",
+];
+
+/// How deep a transaction must be before it is considered final.
+///
+/// # Fields
+///
+/// * `confirmations` - The number of blocks, including its own, a transaction must be buried
+///   under; `1` accepts the first inclusion.
+/// * `poll_interval` - The interval between two polls of the chain.
+/// * `max_resubmissions` - How many times a transaction dropped by a reorg is sent again
+///   before giving up.
+/// * `reinclusion_blocks` - How many blocks a transaction dropped by a reorg has to be
+///   included again before giving up, e.g. when its fee cap fell below the base fee.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Finality {
+    pub confirmations: u64,
+    pub poll_interval: Duration,
+    pub max_resubmissions: usize,
+    pub reinclusion_blocks: u64,
+}
+
+impl Finality {
+    /// Creates a new `Finality` waiting for `confirmations` blocks and resubmitting up to 3
+    /// times, each within [`DEFAULT_REINCLUSION_BLOCKS`] blocks.
+    pub fn new(confirmations: u64) -> Self {
+        Self {
+            confirmations,
+            poll_interval: DEFAULT_FINALITY_POLL_INTERVAL,
+            max_resubmissions: 3,
+            reinclusion_blocks: DEFAULT_REINCLUSION_BLOCKS,
+        }
+    }
+
+    /// Sets the interval between two polls of the chain.
+    pub fn with_poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    /// Sets how many times a transaction dropped by a reorg is sent again.
+    pub fn with_max_resubmissions(mut self, max_resubmissions: usize) -> Self {
+        self.max_resubmissions = max_resubmissions;
+        self
+    }
+
+    /// Sets how many blocks a transaction dropped by a reorg has to be included again.
+    pub fn with_reinclusion_blocks(mut self, reinclusion_blocks: u64) -> Self {
+        self.reinclusion_blocks = reinclusion_blocks;
+        self
+    }
+}
+
+/// A step in the life of a sent transaction.
+///
+/// # Variants
+///
+/// * `Included` - The transaction was included in a block.
+/// * `Reorged` - The block including the transaction left the canonical chain.
+/// * `Resubmitted` - The transaction was sent again after a reorg.
+/// * `Confirmed` - The transaction reached the required confirmations and is final.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TxStatus {
+    Included {
+        block_number: u64,
+        block_hash: BlockHash,
+    },
+    Reorged {
+        block_number: u64,
+        block_hash: BlockHash,
+    },
+    Resubmitted,
+    Confirmed {
+        block_number: u64,
+        block_hash: BlockHash,
+        confirmations: u64,
+    },
+}
+
+/// Follows an included transaction until it is final, resubmitting it if a reorg drops it.
+///
+/// # Arguments
+///
+/// * `rpc_http` - The HTTP URL of the Ethereum RPC endpoint.
+/// * `finality` - The confirmations to wait for.
+/// * `raw` - The EIP-2718 encoding of the signed transaction, sent again after a reorg.
+/// * `tx_hash` - The hash of the transaction.
+/// * `relay` - The relay the transaction was sent through, if any, used to resubmit it.
+/// * `transitions` - The statuses so far, starting with its first inclusion; extended in place.
+/// * `events` - Receives a `Retried` event whenever the transaction is sent again.
+///
+/// # Returns
+///
+/// * `Result<()>` - Ok once the transaction is final, or an error if it reverted after a
+///   reorg or was not included again within `reinclusion_blocks` blocks.
+pub(crate) async fn await_finality(
+    rpc_http: Url,
+    finality: &Finality,
+    raw: &Bytes,
+    tx_hash: TxHash,
+    relay: Option<&BundleRelay>,
+    transitions: &mut Vec<TxStatus>,
+    events: &EventSink,
+) -> Result<()> {
+    let provider = http_provider(rpc_http.clone());
+    let mut included = transitions.iter().rev().find_map(|status| match status {
+        TxStatus::Included {
+            block_number,
+            block_hash,
+        } => Some((*block_number, *block_hash)),
+        _ => None,
+    });
+    let mut resubmissions = 0;
+    // the last block a transaction out of the chain may be included again by
+    let mut deadline = None;
+
+    loop {
+        // a receipt only counts while its block is still canonical
+        let receipt = provider.get_transaction_receipt(tx_hash).await?;
+        let inclusion = match &receipt {
+            Some(receipt) => match (receipt.block_number, receipt.block_hash) {
+                (Some(number), Some(hash)) => {
+                    let canonical = provider
+                        .get_block_by_number(number.into(), BlockTransactionsKind::Hashes)
+                        .await?
+                        .map(|block| block.header.hash);
+                    (canonical == Some(hash)).then_some((number, hash, receipt.status()))
+                }
+                _ => None,
+            },
+            None => None,
+        };
+
+        match (included, inclusion) {
+            (Some(old), Some((number, hash, _))) if old == (number, hash) => {}
+            (previous, Some((number, hash, status))) => {
+                if let Some((block_number, block_hash)) = previous {
+                    transitions.push(TxStatus::Reorged {
+                        block_number,
+                        block_hash,
+                    });
+                }
+                if !status {
+                    return Err(eyre!("transaction {tx_hash} reverted after a reorg"));
+                }
+                transitions.push(TxStatus::Included {
+                    block_number: number,
+                    block_hash: hash,
+                });
+                included = Some((number, hash));
+                deadline = None;
+            }
+            (Some((block_number, block_hash)), None) => {
+                transitions.push(TxStatus::Reorged {
+                    block_number,
+                    block_hash,
+                });
+                included = None;
+
+                if resubmissions == finality.max_resubmissions {
+                    return Err(eyre!(
+                        "transaction {tx_hash} was dropped by a reorg {resubmissions} times"
+                    ));
+                }
+                resubmissions += 1;
+                resubmit(&provider, rpc_http.clone(), raw, tx_hash, relay).await?;
+                transitions.push(TxStatus::Resubmitted);
+                events.emit(TxEventKind::Retried { tx_hash });
+                continue;
+            }
+            (None, None) => {
+                let latest = provider.get_block_number().await?;
+                let deadline = *deadline.get_or_insert(latest + finality.reinclusion_blocks);
+                if latest >= deadline {
+                    return Err(eyre!(
+                        "transaction {tx_hash} was not included again within {} blocks after a reorg",
+                        finality.reinclusion_blocks
+                    ));
+                }
+            }
+        }
+
+        if let Some((block_number, block_hash)) = included {
+            let latest = provider.get_block_number().await?;
+            let confirmations = (latest + 1).saturating_sub(block_number);
+            if confirmations >= finality.confirmations {
+                transitions.push(TxStatus::Confirmed {
+                    block_number,
+                    block_hash,
+                    confirmations,
+                });
+                return Ok(());
+            }
+        }
+
+        tokio::time::sleep(finality.poll_interval).await;
+    }
+}
+
+/// Sends a transaction dropped by a reorg again.
+///
+/// The node usually puts a reorged transaction back in its pool, so a rejection because it is
+/// already known counts as sent; the caller keeps polling until it is included again.
+async fn resubmit(
+    provider: &RootProvider<HttpTransport>,
+    rpc_http: Url,
+    raw: &Bytes,
+    tx_hash: TxHash,
+    relay: Option<&BundleRelay>,
+) -> Result<()> {
+    let sent = match relay {
+        Some(relay) => relay.submit(rpc_http, raw, tx_hash).await.map(|_| ()),
+        None => provider
+            .send_raw_transaction(raw)
+            .await
+            .map(|_| ())
+            .map_err(Into::into),
+    };
+
+    match sent {
+        Err(error) if is_already_sent(&error) => {
+            debug!(%tx_hash, %error, "reorged transaction already known");
+            Ok(())
+        }
+        // the node may have put the transaction back in its pool and included it already
+        Err(_) if provider.get_transaction_receipt(tx_hash).await?.is_some() => Ok(()),
+        sent => sent,
+    }
+}
+
+/// Returns `true` if a send failed because the transaction is already pending or included.
+fn is_already_sent(error: &Report) -> bool {
+    error.chain().any(|cause| {
+        let cause = cause.to_string().to_lowercase();
+        ALREADY_SENT_ERRORS
+            .iter()
+            .any(|message| cause.contains(message))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy::transports::{RpcError, TransportErrorKind};
+    use serde_json::{json, Value};
+    use tokio::{
+        io::{AsyncReadExt, AsyncWriteExt},
+        net::TcpListener,
+    };
+
+    /// Serves a node answering every `eth_sendRawTransaction` with "already known".
+    async fn spawn_node() -> Url {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        tokio::spawn(async move {
+            while let Ok((mut stream, _)) = listener.accept().await {
+                let mut request = Vec::new();
+                let mut buffer = [0; 4096];
+                let body = loop {
+                    let read = stream.read(&mut buffer).await.unwrap();
+                    request.extend_from_slice(&buffer[..read]);
+                    let text = String::from_utf8_lossy(&request).into_owned();
+                    if let Some((_, body)) = text.split_once("\r\n\r\n") {
+                        if let Ok(body) = serde_json::from_str::<Value>(body) {
+                            break body;
+                        }
+                    }
+                };
+
+                let response = match body["method"].as_str() {
+                    Some("eth_sendRawTransaction") => json!({
+                        "jsonrpc": "2.0",
+                        "id": body["id"],
+                        "error": { "code": -32000, "message": "already known" },
+                    }),
+                    _ => json!({ "jsonrpc": "2.0", "id": body["id"], "result": null }),
+                }
+                .to_string();
+                let response = format!(
+                    "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{response}",
+                    response.len()
+                );
+                stream.write_all(response.as_bytes()).await.unwrap();
+            }
+        });
+
+        url.parse().unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_resubmit_already_known() {
+        let rpc_http = spawn_node().await;
+        let provider = http_provider(rpc_http.clone());
+        let (raw, tx_hash) = (Bytes::from_static(&[0x02]), TxHash::with_last_byte(1));
+
+        resubmit(&provider, rpc_http, &raw, tx_hash, None)
+            .await
+            .unwrap();
+    }
+
+    #[test]
+    fn test_is_already_sent() {
+        let payload = r#"{"code":-32000,"message":"nonce too low: next nonce 5, tx nonce 4"}"#;
+        let rpc: RpcError<TransportErrorKind> =
+            RpcError::ErrorResp(serde_json::from_str(payload).unwrap());
+        assert!(is_already_sent(&rpc.into()));
+        assert!(is_already_sent(&eyre!(
+            r#"`eth_sendBundle` rejected by the relay: {{"message":"Already Known"}}"#
+        )));
+        assert!(!is_already_sent(&eyre!("insufficient funds for gas")));
+    }
+}
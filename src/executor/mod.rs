@@ -1,5 +1,28 @@
 mod execute;
-pub use execute::{execute, Execution};
+pub use execute::{execute, send_call, DynSigner, Execution};
+pub(crate) use execute::{send_batch, submit};
 
 mod caller;
-pub use caller::call;
+pub use caller::{call, read_bool, read_uint};
+
+mod options;
+pub use options::ExecuteOptions;
+
+mod request;
+pub(crate) use request::find_function;
+pub use request::CallRequest;
+
+mod finality;
+pub use finality::{
+    Finality, TxStatus, DEFAULT_FINALITY_POLL_INTERVAL, DEFAULT_REINCLUSION_BLOCKS,
+};
+
+mod error;
+pub use error::ErrorClass;
+
+mod control;
+pub use control::RunControl;
+
+mod event;
+pub(crate) use event::EventSink;
+pub use event::{event_channel, track_progress, EventSender, TxEvent, TxEventKind};
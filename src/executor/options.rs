@@ -0,0 +1,44 @@
+use super::{EventSender, Finality, RunControl};
+use crate::{
+    network::{BaseFeeGate, FeeStrategy, NetworkProfile},
+    relay::BundleRelay,
+};
+
+/// Options controlling how transactions are sent.
+///
+/// # Fields
+///
+/// * `network` - The network the RPC endpoint must serve; checked against `eth_chainId` before
+///   anything is sent (optional).
+/// * `fee_strategy` - Overrides the fee strategy of the network profile (optional).
+/// * `relay` - Submits transactions privately through a bundle relay instead of the public
+///   mempool of the RPC endpoint (optional).
+/// * `finality` - The confirmations a transaction needs before it is final, resubmitting it
+///   if a reorg drops it (optional, the first inclusion is final by default).
+/// * `base_fee_gate` - Holds every transaction back until the base fee is low enough, which
+///   pauses a run while fees are high and resumes it once they drop (optional).
+/// * `events` - Receives an event as each transaction progresses, see [`event_channel`]
+///   (optional).
+/// * `control` - Pauses, resumes or aborts the transactions not sent yet, or raises their fee
+///   caps, while a run is going on (optional).
+///
+/// [`event_channel`]: super::event_channel
+#[derive(Debug, Clone, Default)]
+pub struct ExecuteOptions {
+    pub network: Option<NetworkProfile>,
+    pub fee_strategy: Option<FeeStrategy>,
+    pub relay: Option<BundleRelay>,
+    pub finality: Option<Finality>,
+    pub base_fee_gate: Option<BaseFeeGate>,
+    pub events: Option<EventSender>,
+    pub control: Option<RunControl>,
+}
+
+impl ExecuteOptions {
+    /// The fee strategy in effect: the explicit override, then the profile default.
+    pub fn fee_strategy(&self) -> FeeStrategy {
+        self.fee_strategy
+            .or_else(|| self.network.as_ref().map(|network| network.fee_strategy))
+            .unwrap_or_default()
+    }
+}
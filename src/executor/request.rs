@@ -0,0 +1,224 @@
+use alloy::{
+    dyn_abi::{DynSolValue, JsonAbiExt},
+    json_abi::{Function, JsonAbi},
+    primitives::{Address, Bytes, Selector, U256},
+    sol_types::SolCall,
+};
+use eyre::{eyre, Result, WrapErr};
+
+/// A fully encoded contract call, ready to be signed and sent.
+///
+/// # Fields
+///
+/// * `to` - The address of the contract.
+/// * `input` - The calldata of the transaction.
+/// * `value` - The amount of Ether to send with the transaction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CallRequest {
+    pub to: Address,
+    pub input: Bytes,
+    pub value: U256,
+}
+
+impl CallRequest {
+    /// Encodes a call of a function described by a JSON ABI.
+    ///
+    /// # Arguments
+    ///
+    /// * `abi` - The JSON ABI of the contract.
+    /// * `to` - The address of the contract.
+    /// * `function_name` - The name of the function to call.
+    /// * `args` - The arguments to pass to the function.
+    /// * `value` - The amount of Ether to send with the transaction (optional).
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Self>` - The encoded call, or an error if no overload of the function takes
+    ///   that many arguments or the arguments do not match its inputs.
+    pub fn from_abi(
+        abi: &JsonAbi,
+        to: Address,
+        function_name: &str,
+        args: &[DynSolValue],
+        value: Option<U256>,
+    ) -> Result<Self> {
+        let function = find_function(abi, function_name, args.len())?;
+
+        Self::from_function(function, to, args, value)
+    }
+
+    /// Encodes a call of a function already looked up in an ABI.
+    ///
+    /// # Arguments
+    ///
+    /// * `function` - The called function.
+    /// * `to` - The address of the contract.
+    /// * `args` - The arguments to pass to the function.
+    /// * `value` - The amount of Ether to send with the transaction (optional).
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Self>` - The encoded call, or an error if the arguments do not match the
+    ///   inputs of the function.
+    pub fn from_function(
+        function: &Function,
+        to: Address,
+        args: &[DynSolValue],
+        value: Option<U256>,
+    ) -> Result<Self> {
+        let input = function.abi_encode_input(args)?;
+
+        Ok(Self::raw(to, input, value))
+    }
+
+    /// Wraps raw calldata, for contracts without a published ABI.
+    ///
+    /// # Arguments
+    ///
+    /// * `to` - The address of the contract.
+    /// * `input` - The calldata, e.g. the selector `0x1249c58b` of `mint()`.
+    /// * `value` - The amount of Ether to send with the transaction (optional).
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The call.
+    pub fn raw(to: Address, input: impl Into<Bytes>, value: Option<U256>) -> Self {
+        Self {
+            to,
+            input: input.into(),
+            value: value.unwrap_or_default(),
+        }
+    }
+
+    /// Builds a call from a function selector and its already ABI-encoded arguments.
+    ///
+    /// # Arguments
+    ///
+    /// * `to` - The address of the contract.
+    /// * `selector` - The function selector.
+    /// * `encoded_args` - The ABI-encoded arguments, empty for functions without inputs.
+    /// * `value` - The amount of Ether to send with the transaction (optional).
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The call.
+    pub fn from_selector(
+        to: Address,
+        selector: Selector,
+        encoded_args: &[u8],
+        value: Option<U256>,
+    ) -> Self {
+        Self::raw(to, [selector.as_slice(), encoded_args].concat(), value)
+    }
+
+    /// Encodes a call of a function given by its signature, without a full ABI.
+    ///
+    /// # Arguments
+    ///
+    /// * `to` - The address of the contract.
+    /// * `signature` - The function signature, e.g. `"mint(uint256)"` or
+    ///   `"function mint(uint256 quantity) payable"`.
+    /// * `args` - The arguments to pass to the function.
+    /// * `value` - The amount of Ether to send with the transaction (optional).
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Self>` - The encoded call, or an error if the signature is invalid or the
+    ///   arguments do not match its inputs.
+    pub fn from_signature(
+        to: Address,
+        signature: &str,
+        args: &[DynSolValue],
+        value: Option<U256>,
+    ) -> Result<Self> {
+        let function = Function::parse(signature)
+            .wrap_err_with(|| format!("invalid function signature `{signature}`"))?;
+
+        Self::from_function(&function, to, args, value)
+    }
+
+    /// Encodes a typed call generated by `sol!`, such as the ones in [`crate::bindings`].
+    ///
+    /// Unlike [`CallRequest::from_abi`], the function and its arguments are checked at compile
+    /// time and no ABI lookup happens.
+    ///
+    /// # Arguments
+    ///
+    /// * `to` - The address of the contract.
+    /// * `call` - The typed call.
+    /// * `value` - The amount of Ether to send with the transaction (optional).
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The encoded call.
+    pub fn from_call<C: SolCall>(to: Address, call: &C, value: Option<U256>) -> Self {
+        Self {
+            to,
+            input: call.abi_encode().into(),
+            value: value.unwrap_or_default(),
+        }
+    }
+}
+
+/// Finds the overload of a function taking the given number of arguments.
+pub(crate) fn find_function<'a>(
+    abi: &'a JsonAbi,
+    function_name: &str,
+    len: usize,
+) -> Result<&'a Function> {
+    abi.function(function_name)
+        .and_then(|overloads| {
+            overloads
+                .iter()
+                .find(|function| function.inputs.len() == len)
+        })
+        .ok_or_else(|| eyre!("no function `{function_name}` taking {len} arguments in the ABI"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bindings::FreeMint;
+
+    #[test]
+    fn test_typed_matches_dynamic() {
+        let abi =
+            JsonAbi::parse(["function balanceOf(address account) view returns (uint256)"]).unwrap();
+        let (to, account) = (Address::repeat_byte(1), Address::repeat_byte(2));
+
+        let dynamic =
+            CallRequest::from_abi(&abi, to, "balanceOf", &[account.into()], None).unwrap();
+        let typed = CallRequest::from_call(to, &FreeMint::balanceOfCall { account }, None);
+
+        assert_eq!(typed, dynamic);
+    }
+
+    #[test]
+    fn test_raw_modes() {
+        let to = Address::repeat_byte(1);
+        let quantity = U256::from(3);
+        let mint = CallRequest::raw(to, vec![0x12, 0x49, 0xc5, 0x8b], None);
+
+        assert_eq!(
+            CallRequest::from_signature(to, "mint()", &[], None).unwrap(),
+            mint
+        );
+        assert_eq!(
+            CallRequest::from_selector(to, Selector::new([0x12, 0x49, 0xc5, 0x8b]), &[], None),
+            mint
+        );
+
+        let with_args = CallRequest::from_signature(
+            to,
+            "function mint(uint256 quantity) payable",
+            &[quantity.into()],
+            Some(U256::from(1)),
+        )
+        .unwrap();
+        assert_eq!(with_args.input[4..], quantity.to_be_bytes::<32>());
+        assert_eq!(with_args.value, U256::from(1));
+
+        assert!(CallRequest::from_signature(to, "mint(", &[], None).is_err());
+        assert!(CallRequest::from_signature(to, "mint(uint256)", &[], None).is_err());
+    }
+}
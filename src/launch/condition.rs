@@ -0,0 +1,124 @@
+use crate::executor::read_bool;
+use alloy::{
+    json_abi::Event,
+    primitives::{Address, B256},
+    providers::Provider,
+    rpc::types::Filter,
+    transports::{http::reqwest::Url, Transport},
+};
+use eyre::{Result, WrapErr};
+
+/// A condition marking the launch of a mint.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LaunchCondition {
+    /// Code is deployed at the address.
+    Code(Address),
+    /// A `bool` view function without arguments of the contract returns `true`, e.g.
+    /// `mintEnabled()`.
+    View { contract: Address, function: String },
+    /// The contract emits an event with the given topic 0, see [`LaunchCondition::event`].
+    Event { contract: Address, topic: B256 },
+    /// The block with the given number is mined.
+    Block(u64),
+    /// A block with at least the given timestamp is mined.
+    Timestamp(u64),
+}
+
+impl LaunchCondition {
+    /// Creates a condition on an event of a contract.
+    ///
+    /// # Arguments
+    ///
+    /// * `contract` - The address of the emitting contract.
+    /// * `signature` - The event signature, e.g. `SaleStarted(uint256)` or
+    ///   `event SaleStarted(uint256 indexed at)`.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Self>` - The condition, or an error if the signature is invalid.
+    pub fn event(contract: Address, signature: &str) -> Result<Self> {
+        let signature = signature.trim();
+        let event = match signature.strip_prefix("event ") {
+            Some(_) => Event::parse(signature),
+            None => Event::parse(&format!("event {signature}")),
+        }
+        .wrap_err_with(|| format!("invalid event signature `{signature}`"))?;
+
+        Ok(Self::Event {
+            contract,
+            topic: event.selector(),
+        })
+    }
+
+    /// Checks the condition against a newly observed block.
+    ///
+    /// # Arguments
+    ///
+    /// * `provider` - The provider of the RPC endpoint.
+    /// * `rpc_http` - The HTTP URL of the RPC endpoint.
+    /// * `from_block` - The first block not checked yet, where events are searched from.
+    /// * `number` - The number of the latest block.
+    /// * `timestamp` - The timestamp of the latest block.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<bool>` - `true` once the condition is met.
+    pub(crate) async fn is_met<T, P>(
+        &self,
+        provider: &P,
+        rpc_http: Url,
+        from_block: u64,
+        number: u64,
+        timestamp: u64,
+    ) -> Result<bool>
+    where
+        T: Transport + Clone,
+        P: Provider<T>,
+    {
+        match self {
+            Self::Code(address) => Ok(!provider.get_code_at(*address).await?.is_empty()),
+            Self::View { contract, function } => {
+                // the contract may not be deployed yet, which is not an error for a watcher
+                if provider.get_code_at(*contract).await?.is_empty() {
+                    return Ok(false);
+                }
+                read_bool(rpc_http, *contract, function).await
+            }
+            Self::Event { contract, topic } => {
+                let filter = Filter::new()
+                    .address(*contract)
+                    .event_signature(*topic)
+                    .from_block(from_block)
+                    .to_block(number);
+                Ok(!provider.get_logs(&filter).await?.is_empty())
+            }
+            Self::Block(target) => Ok(number >= *target),
+            Self::Timestamp(target) => Ok(timestamp >= *target),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy::primitives::keccak256;
+
+    #[test]
+    fn test_event_condition() {
+        let contract = Address::repeat_byte(1);
+        let expected = LaunchCondition::Event {
+            contract,
+            topic: keccak256("SaleStarted(uint256)"),
+        };
+
+        assert_eq!(
+            LaunchCondition::event(contract, "SaleStarted(uint256)").unwrap(),
+            expected
+        );
+        assert_eq!(
+            LaunchCondition::event(contract, "event SaleStarted(uint256 indexed at)").unwrap(),
+            expected
+        );
+        assert!(LaunchCondition::event(contract, "SaleStarted(").is_err());
+    }
+}
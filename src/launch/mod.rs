@@ -0,0 +1,8 @@
+mod condition;
+pub use condition::LaunchCondition;
+
+mod watcher;
+pub use watcher::{mint_on_launch, LaunchWatcher, DEFAULT_INCLUSION_BLOCKS, DEFAULT_POLL_INTERVAL};
+
+mod schedule;
+pub use schedule::{broadcast_at, prepare_mints, schedule_mint, PreparedMint, ScheduledMintResult};
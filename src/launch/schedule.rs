@@ -0,0 +1,328 @@
+use super::LaunchWatcher;
+use crate::{
+    executor::{ExecuteOptions, TxStatus},
+    mint::{MintEncoder, MintOptions, MintResult, DEFAULT_MINT_GAS_LIMIT},
+    network::{http_provider, FeeStrategy},
+};
+use alloy::{
+    eips::eip2718::Encodable2718,
+    network::{EthereumWallet, TransactionBuilder, TxSigner},
+    primitives::{Address, Bytes, PrimitiveSignature, TxHash, U256},
+    providers::Provider,
+    rpc::types::{TransactionReceipt, TransactionRequest},
+    transports::{http::reqwest::Url, Transport},
+};
+use eyre::{eyre, Result, WrapErr};
+use futures::future::join_all;
+
+/// A mint transaction signed ahead of time, ready to be broadcast.
+///
+/// # Fields
+///
+/// * `signer` - The address of the signer.
+/// * `tx_hash` - The hash of the signed transaction.
+/// * `raw` - The EIP-2718 encoding of the signed transaction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PreparedMint {
+    pub signer: Address,
+    pub tx_hash: TxHash,
+    pub raw: Bytes,
+}
+
+/// The result of a scheduled mint.
+///
+/// # Fields
+///
+/// * `mint` - The result of the mint operation.
+/// * `block_delta` - How many blocks after the target block the transaction was included, if
+///   it was.
+#[derive(Debug)]
+pub struct ScheduledMintResult {
+    pub mint: MintResult,
+    pub block_delta: Option<u64>,
+}
+
+/// Signs the mint transaction of every signer without sending it.
+///
+/// Nonces are taken from the pending state and fees are priced once with the fee strategy of
+/// `options`, so prepared transactions are only valid as long as the signers send nothing
+/// else and the fees stay high enough; a `Fixed` strategy makes the latter explicit.
+///
+/// # Arguments
+///
+/// * `signers` - The signers who will perform the mint operations.
+/// * `rpc_http` - The HTTP URL of the Ethereum RPC endpoint.
+/// * `call` - The call every signer sends, see [`mint_loop_with_options`].
+/// * `options` - The options of the run; `gas_limit` is the gas limit of every transaction,
+///   since mints usually cannot be estimated before the launch. Only `gas_limit` and the
+///   network and fee strategy of `execute` apply to pre-signed mints: setting a relay,
+///   finality, base fee gate, event channel, run control, funds check, supply check or gas
+///   budget is an error, and `concurrency` is ignored since every mint is sent at once.
+///
+/// # Returns
+///
+/// * `Result<Vec<PreparedMint>>` - The signed transactions, in the order of `signers`.
+///
+/// [`mint_loop_with_options`]: crate::mint::mint_loop_with_options
+pub async fn prepare_mints<S, C>(
+    signers: &[S],
+    rpc_http: Url,
+    call: &C,
+    options: &MintOptions,
+) -> Result<Vec<PreparedMint>>
+where
+    S: TxSigner<PrimitiveSignature> + Clone + Send + Sync + 'static,
+    C: MintEncoder + ?Sized,
+{
+    check_options(options)?;
+    if let Some(network) = &options.execute.network {
+        network.verify(rpc_http.clone()).await?;
+    }
+
+    let provider = http_provider(rpc_http);
+    let chain_id = provider.get_chain_id().await?;
+    let gas_limit = options.gas_limit.unwrap_or(DEFAULT_MINT_GAS_LIMIT);
+    let fees = match options.execute.fee_strategy() {
+        FeeStrategy::Eip1559 => {
+            let fees = provider.estimate_eip1559_fees(None).await?;
+            (fees.max_fee_per_gas, Some(fees.max_priority_fee_per_gas))
+        }
+        FeeStrategy::Legacy => (provider.get_gas_price().await?, None),
+        FeeStrategy::Fixed {
+            max_fee_per_gas,
+            max_priority_fee_per_gas,
+        } => (max_fee_per_gas, Some(max_priority_fee_per_gas)),
+    };
+
+    let mut prepared = Vec::with_capacity(signers.len());
+    for (index, signer) in signers.iter().enumerate() {
+        let address = signer.address();
+        let request = call.encode_for(index, address)?;
+        let nonce = provider.get_transaction_count(address).pending().await?;
+
+        let mut tx = TransactionRequest::default()
+            .with_from(address)
+            .with_to(request.to)
+            .with_input(request.input)
+            .with_value(request.value)
+            .with_chain_id(chain_id)
+            .with_nonce(nonce)
+            .with_gas_limit(gas_limit);
+        match fees {
+            (max_fee_per_gas, Some(max_priority_fee_per_gas)) => {
+                tx.set_max_fee_per_gas(max_fee_per_gas);
+                tx.set_max_priority_fee_per_gas(max_priority_fee_per_gas);
+            }
+            (gas_price, None) => tx.set_gas_price(gas_price),
+        }
+
+        let envelope = tx
+            .build(&EthereumWallet::new(signer.clone()))
+            .await
+            .wrap_err_with(|| format!("failed to sign the mint of {address}"))?;
+        prepared.push(PreparedMint {
+            signer: address,
+            tx_hash: *envelope.tx_hash(),
+            raw: envelope.encoded_2718().into(),
+        });
+    }
+
+    Ok(prepared)
+}
+
+/// Broadcasts prepared mints the instant the launch condition is met.
+///
+/// All transactions are sent at once, before any receipt is awaited. A mint not included
+/// within [`LaunchWatcher::inclusion_blocks`] of the launch block, e.g. because its fee cap fell
+/// below the base fee, is reported as not included without holding the others back.
+///
+/// # Arguments
+///
+/// * `watcher` - The watcher of the target block, timestamp or any other launch condition.
+/// * `prepared` - The signed transactions, see [`prepare_mints`].
+/// * `rpc_http` - The HTTP URL of the Ethereum RPC endpoint.
+/// * `options` - The options the transactions were prepared with, for explorer links.
+///
+/// # Returns
+///
+/// * `Result<Vec<ScheduledMintResult>>` - The results in the order of `prepared`, or an error
+///   if the condition could not be watched.
+pub async fn broadcast_at(
+    watcher: &LaunchWatcher,
+    prepared: &[PreparedMint],
+    rpc_http: Url,
+    options: &ExecuteOptions,
+) -> Result<Vec<ScheduledMintResult>> {
+    let provider = http_provider(rpc_http.clone());
+    let target_block = watcher.wait(rpc_http).await?;
+
+    let sent = join_all(
+        prepared
+            .iter()
+            .map(|mint| provider.send_raw_transaction(&mint.raw)),
+    )
+    .await;
+
+    let deadline = target_block.saturating_add(watcher.inclusion_blocks);
+    let included = sent.into_iter().map(|pending| async {
+        let tx_hash = *pending?.tx_hash();
+        let receipt = await_receipt(&provider, tx_hash, deadline, watcher).await?;
+        if !receipt.status() {
+            return Err(eyre!("transaction {} reverted", receipt.transaction_hash));
+        }
+        let gas_cost = U256::from(receipt.gas_used) * U256::from(receipt.effective_gas_price);
+        match (receipt.block_number, receipt.block_hash) {
+            (Some(block_number), Some(block_hash)) => Ok((
+                TxStatus::Included {
+                    block_number,
+                    block_hash,
+                },
+                gas_cost,
+            )),
+            _ => Err(eyre!(
+                "transaction {} has no block",
+                receipt.transaction_hash
+            )),
+        }
+    });
+
+    let results = join_all(included)
+        .await
+        .into_iter()
+        .zip(prepared)
+        .map(|(inclusion, mint)| {
+            let block_delta = match &inclusion {
+                Ok((TxStatus::Included { block_number, .. }, _)) => {
+                    Some(block_number.saturating_sub(target_block))
+                }
+                _ => None,
+            };
+            let explorer_url = options
+                .network
+                .as_ref()
+                .filter(|_| inclusion.is_ok())
+                .and_then(|network| network.tx_url(mint.tx_hash));
+
+            ScheduledMintResult {
+                mint: MintResult {
+                    signer: mint.signer,
+                    explorer_url,
+                    transitions: inclusion
+                        .as_ref()
+                        .map(|&(status, _)| vec![status])
+                        .unwrap_or_default(),
+                    gas_cost: inclusion
+                        .as_ref()
+                        .map(|&(_, gas_cost)| gas_cost)
+                        .unwrap_or_default(),
+                    result: inclusion.map(|_| mint.tx_hash),
+                },
+                block_delta,
+            }
+        })
+        .collect();
+
+    Ok(results)
+}
+
+/// Polls the receipt of a broadcast mint until it exists or the deadline block is passed.
+async fn await_receipt<T, P>(
+    provider: &P,
+    tx_hash: TxHash,
+    deadline: u64,
+    watcher: &LaunchWatcher,
+) -> Result<TransactionReceipt>
+where
+    T: Transport + Clone,
+    P: Provider<T>,
+{
+    loop {
+        // the block is read first, so a receipt included up to it is always seen
+        let latest = provider.get_block_number().await?;
+        if let Some(receipt) = provider.get_transaction_receipt(tx_hash).await? {
+            return Ok(receipt);
+        }
+        if latest >= deadline {
+            return Err(eyre!(
+                "transaction {tx_hash} was not included within {} blocks after the launch",
+                watcher.inclusion_blocks
+            ));
+        }
+        tokio::time::sleep(watcher.poll_interval).await;
+    }
+}
+
+/// Rejects the options that cannot apply to mints signed ahead of time.
+fn check_options(options: &MintOptions) -> Result<()> {
+    let execute = &options.execute;
+    let unsupported: Vec<_> = [
+        ("a relay", execute.relay.is_some()),
+        ("finality", execute.finality.is_some()),
+        ("a base fee gate", execute.base_fee_gate.is_some()),
+        ("an event channel", execute.events.is_some()),
+        ("a run control", execute.control.is_some()),
+        ("a funds check", options.check_funds),
+        ("a supply check", options.supply.is_some()),
+        ("a gas budget", options.gas_budget.is_some()),
+    ]
+    .into_iter()
+    .filter_map(|(option, set)| set.then_some(option))
+    .collect();
+
+    match unsupported.is_empty() {
+        true => Ok(()),
+        false => Err(eyre!(
+            "scheduled mints do not support {}",
+            unsupported.join(", ")
+        )),
+    }
+}
+
+/// Signs every mint ahead of time, then broadcasts them all the instant the launch condition
+/// is met, e.g. when the target block is observed.
+///
+/// # Arguments
+///
+/// * `watcher` - The watcher of the launch condition, usually a `Block` or `Timestamp`.
+/// * `signers` - The signers who will perform the mint operations.
+/// * `rpc_http` - The HTTP URL of the Ethereum RPC endpoint.
+/// * `call` - The call every signer sends.
+/// * `options` - The options of the run, see [`prepare_mints`].
+///
+/// # Returns
+///
+/// * `Result<Vec<ScheduledMintResult>>` - The results in the order of `signers`.
+pub async fn schedule_mint<S, C>(
+    watcher: &LaunchWatcher,
+    signers: &[S],
+    rpc_http: Url,
+    call: &C,
+    options: &MintOptions,
+) -> Result<Vec<ScheduledMintResult>>
+where
+    S: TxSigner<PrimitiveSignature> + Clone + Send + Sync + 'static,
+    C: MintEncoder + ?Sized,
+{
+    let prepared = prepare_mints(signers, rpc_http.clone(), call, options).await?;
+
+    broadcast_at(watcher, &prepared, rpc_http, &options.execute).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mint::SupplyCheck;
+
+    #[test]
+    fn test_check_options() {
+        assert!(check_options(&MintOptions::default()).is_ok());
+
+        let options = MintOptions {
+            check_funds: true,
+            supply: Some(SupplyCheck::default()),
+            ..Default::default()
+        };
+        let error = check_options(&options).unwrap_err().to_string();
+        assert!(error.contains("a funds check, a supply check"));
+    }
+}
@@ -0,0 +1,143 @@
+use super::LaunchCondition;
+use crate::mint::{mint_loop_with_options, MintEncoder, MintOptions, MintResult};
+use crate::network::http_provider;
+use alloy::{
+    eips::BlockNumberOrTag, network::TxSigner, primitives::PrimitiveSignature, providers::Provider,
+    rpc::types::BlockTransactionsKind, transports::http::reqwest::Url,
+};
+use eyre::{eyre, Result};
+use std::time::Duration;
+
+/// The default interval between two polls of the latest block.
+pub const DEFAULT_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// The default number of blocks after the launch within which a scheduled mint must be included.
+pub const DEFAULT_INCLUSION_BLOCKS: u64 = 10;
+
+/// Waits for the launch of a mint by polling the latest block.
+///
+/// The condition is checked once right away, then once per new block.
+///
+/// # Fields
+///
+/// * `condition` - The condition marking the launch.
+/// * `poll_interval` - The interval between two polls of the latest block.
+/// * `timeout` - How long to wait before giving up (optional, waits forever by default).
+/// * `inclusion_blocks` - How many blocks after the launch block a mint broadcast by
+///   [`broadcast_at`](super::broadcast_at) may take to be included before it is reported as not
+///   included.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LaunchWatcher {
+    pub condition: LaunchCondition,
+    pub poll_interval: Duration,
+    pub timeout: Option<Duration>,
+    pub inclusion_blocks: u64,
+}
+
+impl LaunchWatcher {
+    /// Creates a new `LaunchWatcher` polling every [`DEFAULT_POLL_INTERVAL`] without timeout.
+    pub fn new(condition: LaunchCondition) -> Self {
+        Self {
+            condition,
+            poll_interval: DEFAULT_POLL_INTERVAL,
+            timeout: None,
+            inclusion_blocks: DEFAULT_INCLUSION_BLOCKS,
+        }
+    }
+
+    /// Sets the interval between two polls of the latest block.
+    pub fn with_poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    /// Sets how long to wait before giving up.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Sets how many blocks after the launch block a broadcast mint may take to be included.
+    pub fn with_inclusion_blocks(mut self, inclusion_blocks: u64) -> Self {
+        self.inclusion_blocks = inclusion_blocks;
+        self
+    }
+
+    /// Waits until the condition is met.
+    ///
+    /// # Arguments
+    ///
+    /// * `rpc_http` - The HTTP URL of the Ethereum RPC endpoint.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<u64>` - The number of the block the condition was met at, or an error if an
+    ///   RPC request fails or the timeout elapses first.
+    pub async fn wait(&self, rpc_http: Url) -> Result<u64> {
+        match self.timeout {
+            Some(timeout) => tokio::time::timeout(timeout, self.poll(rpc_http))
+                .await
+                .map_err(|_| eyre!("the launch condition was not met within {timeout:?}"))?,
+            None => self.poll(rpc_http).await,
+        }
+    }
+
+    /// Polls the latest block until the condition is met.
+    async fn poll(&self, rpc_http: Url) -> Result<u64> {
+        let provider = http_provider(rpc_http.clone());
+        let mut next_block = None;
+
+        loop {
+            let block = provider
+                .get_block_by_number(BlockNumberOrTag::Latest, BlockTransactionsKind::Hashes)
+                .await?
+                .ok_or_else(|| eyre!("the latest block is not available"))?;
+            let (number, timestamp) = (block.header.number, block.header.timestamp);
+
+            // nothing can change between two polls of the same block
+            if next_block.is_none_or(|next| number >= next) {
+                let from_block = next_block.unwrap_or(number);
+                let met = self
+                    .condition
+                    .is_met(&provider, rpc_http.clone(), from_block, number, timestamp)
+                    .await?;
+                if met {
+                    return Ok(number);
+                }
+                next_block = Some(number + 1);
+            }
+
+            tokio::time::sleep(self.poll_interval).await;
+        }
+    }
+}
+
+/// Waits for the launch of a mint, then mints with every signer as soon as it is met.
+///
+/// # Arguments
+///
+/// * `watcher` - The watcher of the launch condition.
+/// * `signers` - A vector of signers who will perform the mint operations.
+/// * `rpc_http` - The HTTP URL of the Ethereum RPC endpoint.
+/// * `call` - The call every signer sends, see [`mint_loop_with_options`].
+/// * `options` - The options of the run.
+///
+/// # Returns
+///
+/// * `Result<Vec<MintResult>>` - The results of the mint operations, or an error if the
+///   condition could not be watched.
+pub async fn mint_on_launch<S, C>(
+    watcher: &LaunchWatcher,
+    signers: Vec<S>,
+    rpc_http: Url,
+    call: &C,
+    options: &MintOptions,
+) -> Result<Vec<MintResult>>
+where
+    S: TxSigner<PrimitiveSignature> + Clone + Send + Sync + 'static,
+    C: MintEncoder + ?Sized,
+{
+    watcher.wait(rpc_http.clone()).await?;
+
+    mint_loop_with_options(signers, rpc_http, call, options).await
+}
@@ -1,7 +1,27 @@
 pub mod account;
 
+pub mod artifact;
+
+pub mod bindings;
+
 pub mod executor;
 
 pub mod distributor;
 
 pub mod mint;
+
+pub mod allowlist;
+
+pub mod network;
+
+pub mod relay;
+
+pub mod launch;
+
+pub mod campaign;
+
+#[cfg(feature = "metrics")]
+pub mod metrics;
+
+#[cfg(feature = "dashboard")]
+pub mod dashboard;
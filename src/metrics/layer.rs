@@ -0,0 +1,85 @@
+use super::Metrics;
+use alloy::{
+    rpc::json_rpc::{RequestPacket, ResponsePacket},
+    transports::{http::reqwest::Url, TransportError, TransportFut},
+};
+use std::{
+    sync::Arc,
+    task::{Context, Poll},
+    time::Instant,
+};
+use tower::{Layer, Service};
+
+/// A transport layer timing every RPC request into the [`Metrics::global`] registry.
+#[derive(Debug, Clone)]
+pub(crate) struct RpcMetricsLayer {
+    endpoint: Arc<str>,
+}
+
+impl RpcMetricsLayer {
+    /// Creates a layer labelling the requests with the host of `rpc_http`, which unlike the
+    /// full URL never holds an API key.
+    pub(crate) fn new(rpc_http: &Url) -> Self {
+        let host = rpc_http.host_str().unwrap_or_default();
+        let endpoint = match rpc_http.port() {
+            Some(port) => format!("{host}:{port}"),
+            None => host.to_owned(),
+        };
+
+        Self {
+            endpoint: endpoint.into(),
+        }
+    }
+}
+
+impl<S> Layer<S> for RpcMetricsLayer {
+    type Service = RpcMetricsService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        RpcMetricsService {
+            inner,
+            endpoint: self.endpoint.clone(),
+        }
+    }
+}
+
+/// The transport wrapped by an [`RpcMetricsLayer`].
+#[derive(Debug, Clone)]
+pub(crate) struct RpcMetricsService<S> {
+    inner: S,
+    endpoint: Arc<str>,
+}
+
+impl<S> Service<RequestPacket> for RpcMetricsService<S>
+where
+    S: Service<
+        RequestPacket,
+        Response = ResponsePacket,
+        Error = TransportError,
+        Future = TransportFut<'static>,
+    >,
+{
+    type Response = ResponsePacket;
+    type Error = TransportError;
+    type Future = TransportFut<'static>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: RequestPacket) -> Self::Future {
+        let method = match &request {
+            RequestPacket::Single(request) => request.method().to_owned(),
+            RequestPacket::Batch(_) => "batch".to_owned(),
+        };
+        let endpoint = self.endpoint.clone();
+        let started = Instant::now();
+        let response = self.inner.call(request);
+
+        Box::pin(async move {
+            let response = response.await;
+            Metrics::global().observe_rpc(&method, &endpoint, started.elapsed());
+            response
+        })
+    }
+}
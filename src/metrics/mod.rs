@@ -0,0 +1,8 @@
+mod registry;
+pub use registry::{InFlight, Metrics, RPC_LATENCY_BUCKETS, TRANSACTION_LATENCY_BUCKETS};
+
+mod layer;
+pub(crate) use layer::{RpcMetricsLayer, RpcMetricsService};
+
+mod server;
+pub use server::MetricsServer;
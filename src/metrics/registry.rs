@@ -0,0 +1,334 @@
+use std::{
+    collections::BTreeMap,
+    fmt::Write,
+    sync::{
+        atomic::{AtomicI64, AtomicU64, Ordering},
+        Mutex, OnceLock,
+    },
+    time::Duration,
+};
+
+/// The upper bounds of the RPC latency buckets, in seconds.
+pub const RPC_LATENCY_BUCKETS: &[f64] = &[0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0];
+
+/// The upper bounds of the transaction latency buckets, from signing to finality, in seconds.
+pub const TRANSACTION_LATENCY_BUCKETS: &[f64] =
+    &[0.5, 1.0, 2.0, 5.0, 10.0, 20.0, 30.0, 60.0, 120.0, 300.0];
+
+/// The metrics of a process, rendered in the Prometheus text format.
+///
+/// `execute`, `distribute` and `mint_loop` record into the [`Metrics::global`] registry; serve
+/// it with a [`MetricsServer`](super::MetricsServer).
+#[derive(Debug, Default)]
+pub struct Metrics {
+    transactions_sent: AtomicU64,
+    transactions_confirmed: AtomicU64,
+    transactions_reverted: AtomicU64,
+    transactions_failed: AtomicU64,
+    transactions_in_flight: AtomicI64,
+    accounts_remaining: AtomicI64,
+    gas_spent: Mutex<u128>,
+    transaction_latency: Mutex<Option<Histogram>>,
+    rpc_latency: Mutex<BTreeMap<(String, String), Histogram>>,
+}
+
+/// Marks a transaction in flight until dropped.
+#[derive(Debug)]
+pub struct InFlight<'a> {
+    metrics: &'a Metrics,
+}
+
+impl Drop for InFlight<'_> {
+    fn drop(&mut self) {
+        self.metrics
+            .transactions_in_flight
+            .fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+impl Metrics {
+    /// Returns the registry the library records into.
+    pub fn global() -> &'static Self {
+        static METRICS: OnceLock<Metrics> = OnceLock::new();
+        METRICS.get_or_init(Self::default)
+    }
+
+    /// Counts a transaction sent to the network and marks it in flight until the guard drops.
+    pub fn transaction_sent(&self) -> InFlight<'_> {
+        self.transactions_sent.fetch_add(1, Ordering::Relaxed);
+        self.transactions_in_flight.fetch_add(1, Ordering::Relaxed);
+        InFlight { metrics: self }
+    }
+
+    /// Counts a transaction included (and final, if required) with a success status.
+    ///
+    /// # Arguments
+    ///
+    /// * `gas_cost` - What the transaction paid for gas, in wei.
+    /// * `latency` - The time from signing to finality.
+    pub fn transaction_confirmed(&self, gas_cost: u128, latency: Duration) {
+        self.transactions_confirmed.fetch_add(1, Ordering::Relaxed);
+        self.transaction_included(gas_cost, latency);
+    }
+
+    /// Counts a transaction included with a failure status.
+    ///
+    /// # Arguments
+    ///
+    /// * `gas_cost` - What the transaction paid for gas, in wei.
+    /// * `latency` - The time from signing to inclusion.
+    pub fn transaction_reverted(&self, gas_cost: u128, latency: Duration) {
+        self.transactions_reverted.fetch_add(1, Ordering::Relaxed);
+        self.transaction_included(gas_cost, latency);
+    }
+
+    /// Counts a sent transaction that failed without a receipt, e.g. dropped or not final.
+    ///
+    /// Transactions that were never accepted by the node or the relay, e.g. aborted, held by a
+    /// gate or reverting during gas estimation, are not counted.
+    ///
+    /// # Arguments
+    ///
+    /// * `reverted` - Whether it failed because the call reverts.
+    pub fn transaction_failed(&self, reverted: bool) {
+        match reverted {
+            true => &self.transactions_reverted,
+            false => &self.transactions_failed,
+        }
+        .fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Sets the number of accounts whose transaction is not done yet.
+    pub fn set_accounts_remaining(&self, accounts: usize) {
+        self.accounts_remaining.store(
+            i64::try_from(accounts).unwrap_or(i64::MAX),
+            Ordering::Relaxed,
+        );
+    }
+
+    /// Marks one more account as done.
+    pub fn account_done(&self) {
+        self.accounts_remaining.fetch_sub(1, Ordering::Relaxed);
+    }
+
+    /// Records the latency of an RPC request.
+    ///
+    /// # Arguments
+    ///
+    /// * `method` - The JSON-RPC method, e.g. `eth_sendRawTransaction`.
+    /// * `endpoint` - The host of the RPC endpoint, never its full URL which may hold a key.
+    /// * `latency` - The time until the response.
+    pub fn observe_rpc(&self, method: &str, endpoint: &str, latency: Duration) {
+        self.rpc_latency
+            .lock()
+            .unwrap()
+            .entry((method.to_owned(), endpoint.to_owned()))
+            .or_insert_with(|| Histogram::new(RPC_LATENCY_BUCKETS))
+            .observe(latency.as_secs_f64());
+    }
+
+    /// Renders every metric in the Prometheus text exposition format.
+    pub fn render(&self) -> String {
+        let mut out = String::new();
+
+        let counters = [
+            (
+                "stormint_transactions_sent_total",
+                "Transactions sent to the network.",
+                &self.transactions_sent,
+            ),
+            (
+                "stormint_transactions_confirmed_total",
+                "Transactions included with a success status, and final if required.",
+                &self.transactions_confirmed,
+            ),
+            (
+                "stormint_transactions_reverted_total",
+                "Transactions that reverted after being sent.",
+                &self.transactions_reverted,
+            ),
+            (
+                "stormint_transactions_failed_total",
+                "Transactions that failed for any other reason.",
+                &self.transactions_failed,
+            ),
+        ];
+        for (name, help, counter) in counters {
+            write_header(&mut out, name, help, "counter");
+            let _ = writeln!(out, "{name} {}", counter.load(Ordering::Relaxed));
+        }
+
+        let gauges = [
+            (
+                "stormint_transactions_in_flight",
+                "Transactions sent and not yet included, or final if required.",
+                &self.transactions_in_flight,
+            ),
+            (
+                "stormint_accounts_remaining",
+                "Accounts of the current run whose transaction is not done yet.",
+                &self.accounts_remaining,
+            ),
+        ];
+        for (name, help, gauge) in gauges {
+            write_header(&mut out, name, help, "gauge");
+            let _ = writeln!(out, "{name} {}", gauge.load(Ordering::Relaxed));
+        }
+
+        let name = "stormint_gas_spent_wei_total";
+        write_header(
+            &mut out,
+            name,
+            "Gas paid by included transactions, in wei.",
+            "counter",
+        );
+        let _ = writeln!(out, "{name} {}", self.gas_spent.lock().unwrap());
+
+        let name = "stormint_transaction_latency_seconds";
+        write_header(
+            &mut out,
+            name,
+            "Time from signing a transaction to its inclusion or finality.",
+            "histogram",
+        );
+        if let Some(histogram) = &*self.transaction_latency.lock().unwrap() {
+            histogram.render(&mut out, name, "");
+        }
+
+        let name = "stormint_rpc_latency_seconds";
+        write_header(&mut out, name, "Latency of RPC requests.", "histogram");
+        for ((method, endpoint), histogram) in &*self.rpc_latency.lock().unwrap() {
+            let labels = format!(
+                "method=\"{}\",endpoint=\"{}\"",
+                escape(method),
+                escape(endpoint)
+            );
+            histogram.render(&mut out, name, &labels);
+        }
+
+        out
+    }
+
+    /// Records the gas and latency of an included transaction.
+    fn transaction_included(&self, gas_cost: u128, latency: Duration) {
+        let mut gas_spent = self.gas_spent.lock().unwrap();
+        *gas_spent = gas_spent.saturating_add(gas_cost);
+        drop(gas_spent);
+
+        self.transaction_latency
+            .lock()
+            .unwrap()
+            .get_or_insert_with(|| Histogram::new(TRANSACTION_LATENCY_BUCKETS))
+            .observe(latency.as_secs_f64());
+    }
+}
+
+/// A histogram with fixed buckets.
+#[derive(Debug)]
+struct Histogram {
+    bounds: &'static [f64],
+    counts: Vec<u64>,
+    sum: f64,
+    count: u64,
+}
+
+impl Histogram {
+    fn new(bounds: &'static [f64]) -> Self {
+        Self {
+            bounds,
+            counts: vec![0; bounds.len()],
+            sum: 0.0,
+            count: 0,
+        }
+    }
+
+    fn observe(&mut self, value: f64) {
+        if let Some(bucket) = self.bounds.iter().position(|&bound| value <= bound) {
+            self.counts[bucket] += 1;
+        }
+        self.sum += value;
+        self.count += 1;
+    }
+
+    /// Renders the cumulative buckets, the sum and the count of the histogram.
+    fn render(&self, out: &mut String, name: &str, labels: &str) {
+        let separator = if labels.is_empty() { "" } else { "," };
+
+        let mut cumulative = 0;
+        for (bound, count) in self.bounds.iter().zip(&self.counts) {
+            cumulative += count;
+            let _ = writeln!(
+                out,
+                "{name}_bucket{{{labels}{separator}le=\"{bound}\"}} {cumulative}"
+            );
+        }
+        let _ = writeln!(
+            out,
+            "{name}_bucket{{{labels}{separator}le=\"+Inf\"}} {}",
+            self.count
+        );
+
+        let labels = if labels.is_empty() {
+            String::new()
+        } else {
+            format!("{{{labels}}}")
+        };
+        let _ = writeln!(out, "{name}_sum{labels} {}", self.sum);
+        let _ = writeln!(out, "{name}_count{labels} {}", self.count);
+    }
+}
+
+fn write_header(out: &mut String, name: &str, help: &str, kind: &str) {
+    let _ = writeln!(out, "# HELP {name} {help}");
+    let _ = writeln!(out, "# TYPE {name} {kind}");
+}
+
+/// Escapes a label value.
+fn escape(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render() {
+        let metrics = Metrics::default();
+        metrics.set_accounts_remaining(3);
+
+        let in_flight = metrics.transaction_sent();
+        assert!(metrics
+            .render()
+            .contains("stormint_transactions_in_flight 1\n"));
+        drop(in_flight);
+        metrics.transaction_confirmed(21_000, Duration::from_millis(1_500));
+        metrics.account_done();
+        metrics.transaction_failed(true);
+        metrics.observe_rpc("eth_chainId", "localhost:8545", Duration::from_millis(20));
+        metrics.observe_rpc("eth_chainId", "localhost:8545", Duration::from_secs(60));
+
+        let rendered = metrics.render();
+        for line in [
+            "# TYPE stormint_transactions_sent_total counter",
+            "stormint_transactions_sent_total 1",
+            "stormint_transactions_confirmed_total 1",
+            "stormint_transactions_reverted_total 1",
+            "stormint_transactions_failed_total 0",
+            "stormint_transactions_in_flight 0",
+            "stormint_accounts_remaining 2",
+            "stormint_gas_spent_wei_total 21000",
+            "stormint_transaction_latency_seconds_bucket{le=\"1\"} 0",
+            "stormint_transaction_latency_seconds_bucket{le=\"2\"} 1",
+            "stormint_transaction_latency_seconds_count 1",
+            "stormint_rpc_latency_seconds_bucket{method=\"eth_chainId\",endpoint=\"localhost:8545\",le=\"0.025\"} 1",
+            "stormint_rpc_latency_seconds_bucket{method=\"eth_chainId\",endpoint=\"localhost:8545\",le=\"+Inf\"} 2",
+            "stormint_rpc_latency_seconds_count{method=\"eth_chainId\",endpoint=\"localhost:8545\"} 2",
+        ] {
+            assert!(rendered.lines().any(|l| l == line), "missing `{line}`");
+        }
+    }
+}
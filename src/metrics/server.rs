@@ -0,0 +1,150 @@
+use super::Metrics;
+use eyre::{Result, WrapErr};
+use std::{net::SocketAddr, time::Duration};
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::{TcpListener, TcpStream, ToSocketAddrs},
+    task::JoinHandle,
+    time::{sleep, timeout},
+};
+use tracing::{debug, info, warn};
+
+/// The largest request head read before answering.
+const MAX_REQUEST_SIZE: usize = 8 * 1024;
+
+/// How long a client may take to send its request head before the connection is closed.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// How long to wait before accepting again after a failed accept, e.g. when out of descriptors.
+const ACCEPT_BACKOFF: Duration = Duration::from_millis(100);
+
+/// A local HTTP server exposing the [`Metrics::global`] registry on `/metrics`.
+///
+/// The server runs in the background until dropped.
+#[derive(Debug)]
+pub struct MetricsServer {
+    local_addr: SocketAddr,
+    task: JoinHandle<()>,
+}
+
+impl MetricsServer {
+    /// Starts serving the metrics.
+    ///
+    /// # Arguments
+    ///
+    /// * `addr` - The address to listen on, e.g. `127.0.0.1:9898`; port `0` picks a free one.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Self>` - The running server, or an error if the address cannot be bound.
+    pub async fn bind(addr: impl ToSocketAddrs) -> Result<Self> {
+        let listener = TcpListener::bind(addr)
+            .await
+            .wrap_err("failed to bind the metrics endpoint")?;
+        let local_addr = listener.local_addr()?;
+        info!(%local_addr, "serving metrics");
+
+        let task = tokio::spawn(async move {
+            loop {
+                let stream = match listener.accept().await {
+                    Ok((stream, _)) => stream,
+                    Err(error) => {
+                        warn!(%error, "failed to accept a metrics connection");
+                        sleep(ACCEPT_BACKOFF).await;
+                        continue;
+                    }
+                };
+                tokio::spawn(async move {
+                    if let Err(error) = respond(stream).await {
+                        debug!(%error, "metrics request failed");
+                    }
+                });
+            }
+        });
+
+        Ok(Self { local_addr, task })
+    }
+
+    /// Returns the address the server listens on.
+    pub fn local_addr(&self) -> SocketAddr {
+        self.local_addr
+    }
+}
+
+impl Drop for MetricsServer {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+/// Answers a single request, then closes the connection.
+///
+/// Clients that do not send their request head within [`REQUEST_TIMEOUT`] are disconnected.
+async fn respond(mut stream: TcpStream) -> Result<()> {
+    let request = timeout(REQUEST_TIMEOUT, read_request(&mut stream))
+        .await
+        .wrap_err("the metrics request timed out")??;
+
+    let request = String::from_utf8_lossy(&request);
+    let mut request_line = request.lines().next().unwrap_or_default().split(' ');
+    let (method, path) = (request_line.next(), request_line.next());
+
+    let (status, content_type, body) = match (method, path) {
+        (Some("GET"), Some("/metrics")) => (
+            "200 OK",
+            "text/plain; version=0.0.4",
+            Metrics::global().render(),
+        ),
+        _ => ("404 Not Found", "text/plain", "not found\n".to_owned()),
+    };
+    let response = format!(
+        "HTTP/1.1 {status}\r\ncontent-type: {content_type}\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{body}",
+        body.len()
+    );
+    stream.write_all(response.as_bytes()).await?;
+    stream.shutdown().await?;
+
+    Ok(())
+}
+
+/// Reads a request head, up to [`MAX_REQUEST_SIZE`] bytes.
+async fn read_request(stream: &mut TcpStream) -> Result<Vec<u8>> {
+    let mut request = Vec::new();
+    let mut buffer = [0; 1024];
+    while !request.windows(4).any(|window| window == b"\r\n\r\n") {
+        let read = stream.read(&mut buffer).await?;
+        if read == 0 || request.len() + read > MAX_REQUEST_SIZE {
+            break;
+        }
+        request.extend_from_slice(&buffer[..read]);
+    }
+
+    Ok(request)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy::transports::http::reqwest::Client;
+
+    #[tokio::test]
+    async fn test_serve_metrics() {
+        let server = MetricsServer::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", server.local_addr());
+
+        let response = Client::new()
+            .get(format!("{url}/metrics"))
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 200);
+        assert!(response
+            .text()
+            .await
+            .unwrap()
+            .contains("# TYPE stormint_transactions_sent_total counter"));
+
+        let response = Client::new().get(url).send().await.unwrap();
+        assert_eq!(response.status(), 404);
+    }
+}
@@ -0,0 +1,315 @@
+use crate::executor::find_function;
+use alloy::{
+    dyn_abi::{DynSolType, DynSolValue, Specifier},
+    json_abi::JsonAbi,
+    primitives::Address,
+};
+use eyre::{eyre, Result, WrapErr};
+use serde_json::Value;
+use std::{collections::HashMap, fs, path::Path};
+
+/// The placeholder replaced by the address of the signer in templated arguments.
+pub const SIGNER_PLACEHOLDER: &str = "{signer}";
+
+/// The placeholder replaced by the index of the signer in templated arguments.
+pub const INDEX_PLACEHOLDER: &str = "{index}";
+
+/// Arguments of the mint call for each signer, loaded from a JSON file keyed by address.
+///
+/// The file maps addresses to argument lists, e.g.
+/// `{ "0xf39F…2266": ["5", ["0xab…", "0xcd…"]] }`. Values are parsed according to the inputs
+/// of the called function, see [`coerce_json_args`]; nested arrays are used for array and
+/// tuple inputs.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SignerArgs {
+    args: HashMap<Address, Vec<Value>>,
+}
+
+impl SignerArgs {
+    /// Parses the arguments from JSON content.
+    ///
+    /// # Arguments
+    ///
+    /// * `content` - A JSON object mapping addresses to argument lists.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Self>` - The arguments on success.
+    pub fn from_json_str(content: &str) -> Result<Self> {
+        let args: HashMap<Address, Vec<Value>> =
+            serde_json::from_str(content).wrap_err("signer arguments are not valid")?;
+
+        for (address, values) in &args {
+            values
+                .iter()
+                .try_for_each(check_arg)
+                .wrap_err_with(|| format!("invalid arguments for {address}"))?;
+        }
+
+        Ok(Self { args })
+    }
+
+    /// Loads the arguments from a JSON file.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the file.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Self>` - The arguments on success.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref();
+        let content = fs::read_to_string(path)
+            .wrap_err_with(|| format!("failed to read signer arguments `{}`", path.display()))?;
+
+        Self::from_json_str(&content)
+            .wrap_err_with(|| format!("invalid signer arguments `{}`", path.display()))
+    }
+
+    /// Returns the arguments of a signer, failing if the file has none.
+    pub fn get(&self, signer: Address) -> Result<&[Value]> {
+        self.args
+            .get(&signer)
+            .map(Vec::as_slice)
+            .ok_or_else(|| eyre!("no arguments for signer {signer}"))
+    }
+
+    /// Returns the number of signers with arguments.
+    pub fn len(&self) -> usize {
+        self.args.len()
+    }
+
+    /// Returns `true` if no signer has arguments.
+    pub fn is_empty(&self) -> bool {
+        self.args.is_empty()
+    }
+}
+
+/// Checks that a JSON value only holds strings, numbers, booleans and arrays of them.
+fn check_arg(value: &Value) -> Result<()> {
+    match value {
+        Value::String(_) | Value::Number(_) | Value::Bool(_) => Ok(()),
+        Value::Array(values) => values.iter().try_for_each(check_arg),
+        _ => Err(eyre!("unsupported argument `{value}`")),
+    }
+}
+
+/// Replaces the [`SIGNER_PLACEHOLDER`] and [`INDEX_PLACEHOLDER`] in templated arguments.
+///
+/// # Arguments
+///
+/// * `template` - The templated arguments, e.g. `["{signer}", "1"]`.
+/// * `signer` - The address of the signer.
+/// * `index` - The index of the signer.
+///
+/// # Returns
+///
+/// * `Vec<String>` - The arguments of the signer.
+pub fn expand_args<S: AsRef<str>>(template: &[S], signer: Address, index: usize) -> Vec<String> {
+    template
+        .iter()
+        .map(|arg| {
+            arg.as_ref()
+                .replace(SIGNER_PLACEHOLDER, &signer.to_string())
+                .replace(INDEX_PLACEHOLDER, &index.to_string())
+        })
+        .collect()
+}
+
+/// Replaces the placeholders in the strings of JSON arguments, see [`expand_args`].
+///
+/// # Arguments
+///
+/// * `template` - The templated arguments, e.g. from [`SignerArgs::get`].
+/// * `signer` - The address of the signer.
+/// * `index` - The index of the signer.
+///
+/// # Returns
+///
+/// * `Vec<Value>` - The arguments of the signer.
+pub fn expand_json_args(template: &[Value], signer: Address, index: usize) -> Vec<Value> {
+    template
+        .iter()
+        .map(|value| match value {
+            Value::String(arg) => Value::String(expand_args(&[arg], signer, index).remove(0)),
+            Value::Array(values) => Value::Array(expand_json_args(values, signer, index)),
+            value => value.clone(),
+        })
+        .collect()
+}
+
+/// Parses string arguments according to the inputs of a function.
+///
+/// # Arguments
+///
+/// * `abi` - The JSON ABI of the contract.
+/// * `function_name` - The name of the function.
+/// * `args` - The arguments, e.g. `["0xf39F…2266", "5", "[0xab…,0xcd…]"]`.
+///
+/// # Returns
+///
+/// * `Result<Vec<DynSolValue>>` - The parsed arguments, or an error if no overload of the
+///   function takes that many arguments or an argument does not match its input type.
+pub fn coerce_args<S: AsRef<str>>(
+    abi: &JsonAbi,
+    function_name: &str,
+    args: &[S],
+) -> Result<Vec<DynSolValue>> {
+    let function = find_function(abi, function_name, args.len())?;
+
+    function
+        .inputs
+        .iter()
+        .zip(args)
+        .map(|(param, arg)| {
+            let arg = arg.as_ref();
+            param
+                .resolve()?
+                .coerce_str(arg)
+                .wrap_err_with(|| format!("invalid value `{arg}` for `{}`", param.ty))
+        })
+        .collect()
+}
+
+/// Parses JSON arguments according to the inputs of a function.
+///
+/// Strings, numbers and booleans are parsed like [`coerce_args`]; arrays map element-wise to
+/// array, fixed array and tuple inputs, so string elements are never split on commas.
+///
+/// # Arguments
+///
+/// * `abi` - The JSON ABI of the contract.
+/// * `function_name` - The name of the function.
+/// * `args` - The arguments, e.g. `["5", ["0xab…", "0xcd…"]]`.
+///
+/// # Returns
+///
+/// * `Result<Vec<DynSolValue>>` - The parsed arguments, or an error if no overload of the
+///   function takes that many arguments or an argument does not match its input type.
+pub fn coerce_json_args(
+    abi: &JsonAbi,
+    function_name: &str,
+    args: &[Value],
+) -> Result<Vec<DynSolValue>> {
+    let function = find_function(abi, function_name, args.len())?;
+
+    function
+        .inputs
+        .iter()
+        .zip(args)
+        .map(|(param, arg)| {
+            coerce_json(&param.resolve()?, arg)
+                .wrap_err_with(|| format!("invalid value `{arg}` for `{}`", param.ty))
+        })
+        .collect()
+}
+
+/// Parses a JSON value as a value of the given type.
+fn coerce_json(ty: &DynSolType, value: &Value) -> Result<DynSolValue> {
+    let values = match value {
+        Value::String(value) => return Ok(ty.coerce_str(value)?),
+        Value::Number(value) => return Ok(ty.coerce_str(&value.to_string())?),
+        Value::Bool(value) => return Ok(ty.coerce_str(&value.to_string())?),
+        Value::Array(values) => values,
+        _ => return Err(eyre!("unsupported argument `{value}`")),
+    };
+
+    let coerce_all = |types: &mut dyn Iterator<Item = &DynSolType>| {
+        types
+            .zip(values)
+            .map(|(ty, value)| coerce_json(ty, value))
+            .collect::<Result<Vec<_>>>()
+    };
+    match ty {
+        DynSolType::Array(inner) => Ok(DynSolValue::Array(coerce_all(&mut std::iter::repeat(
+            inner.as_ref(),
+        ))?)),
+        DynSolType::FixedArray(inner, len) if *len == values.len() => Ok(DynSolValue::FixedArray(
+            coerce_all(&mut std::iter::repeat(inner.as_ref()))?,
+        )),
+        DynSolType::Tuple(types) if types.len() == values.len() => {
+            Ok(DynSolValue::Tuple(coerce_all(&mut types.iter())?))
+        }
+        _ => Err(eyre!(
+            "expected a `{}`, got an array of {} values",
+            ty.sol_type_name(),
+            values.len()
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy::primitives::{B256, U256};
+
+    #[test]
+    fn test_signer_args() {
+        let alice = Address::repeat_byte(1);
+        let content = format!(
+            r#"{{"{alice}": [5, ["0x{0}", "0x{0}"], true]}}"#,
+            "ab".repeat(32)
+        );
+        let args = SignerArgs::from_json_str(&content).unwrap();
+
+        let abi =
+            JsonAbi::parse(["function mint(uint256 amount, bytes32[] proof, bool flag)"]).unwrap();
+        let values = coerce_json_args(&abi, "mint", args.get(alice).unwrap()).unwrap();
+
+        assert_eq!(values[0], DynSolValue::from(U256::from(5)));
+        assert_eq!(
+            values[1],
+            DynSolValue::Array(vec![
+                DynSolValue::FixedBytes(B256::repeat_byte(0xab), 32);
+                2
+            ])
+        );
+        assert!(args.get(Address::repeat_byte(2)).is_err());
+        assert!(SignerArgs::from_json_str(&format!(r#"{{"{alice}": [{{}}]}}"#)).is_err());
+    }
+
+    #[test]
+    fn test_json_args_keep_strings_whole() {
+        let alice = Address::repeat_byte(1);
+        let content = format!(r#"{{"{alice}": [["a,b", "[c]"], [7, ["{{index}}", "x"]]]}}"#);
+        let args = SignerArgs::from_json_str(&content).unwrap();
+        let args = expand_json_args(args.get(alice).unwrap(), alice, 3);
+
+        let abi =
+            JsonAbi::parse(["function mint(string[] names, (uint8,string[]) extra)"]).unwrap();
+        let values = coerce_json_args(&abi, "mint", &args).unwrap();
+
+        assert_eq!(
+            values[0],
+            DynSolValue::Array(vec!["a,b".to_owned().into(), "[c]".to_owned().into()])
+        );
+        assert_eq!(
+            values[1],
+            DynSolValue::Tuple(vec![
+                DynSolValue::Uint(U256::from(7), 8),
+                DynSolValue::Array(vec!["3".to_owned().into(), "x".to_owned().into()]),
+            ])
+        );
+
+        let abi = JsonAbi::parse(["function mint(uint256[2] amounts)"]).unwrap();
+        assert!(coerce_json_args(&abi, "mint", &[serde_json::json!([1, 2, 3])]).is_err());
+    }
+
+    #[test]
+    fn test_expand_args() {
+        let signer = Address::repeat_byte(1);
+        let args = expand_args(&["{signer}", "{index}", "7"], signer, 3);
+
+        assert_eq!(args, [signer.to_string(), "3".to_owned(), "7".to_owned()]);
+    }
+
+    #[test]
+    fn test_coerce_args_mismatch() {
+        let abi = JsonAbi::parse(["function mint(uint256 amount)"]).unwrap();
+
+        assert!(coerce_args(&abi, "mint", &["1", "2"]).is_err());
+        assert!(coerce_args(&abi, "mint", &["lots"]).is_err());
+        assert!(coerce_args(&abi, "claim", &["1"]).is_err());
+    }
+}
@@ -0,0 +1,97 @@
+use alloy::primitives::U256;
+use std::sync::Mutex;
+
+/// Tracks the gas spent by a run against a budget.
+///
+/// Before a mint is sent, its projected cost is reserved; once it is included, the reservation
+/// is replaced by its actual cost. Mints are projected to cost the average of the included
+/// ones, or the worst case given at creation until one is included.
+#[derive(Debug)]
+pub(crate) struct GasBudget {
+    budget: U256,
+    worst_case: U256,
+    state: Mutex<BudgetState>,
+}
+
+#[derive(Debug, Default)]
+struct BudgetState {
+    spent: U256,
+    reserved: U256,
+    included: u64,
+}
+
+impl GasBudget {
+    /// Creates a new budget, projecting mints to cost `worst_case` until one is included.
+    pub(crate) fn new(budget: U256, worst_case: U256) -> Self {
+        Self {
+            budget,
+            worst_case,
+            state: Mutex::default(),
+        }
+    }
+
+    /// Reserves the projected cost of a mint, if it fits in what is left of the budget.
+    pub(crate) fn reserve(&self) -> Option<U256> {
+        let mut state = self.state.lock().unwrap();
+
+        let projected = match state.included {
+            0 => self.worst_case,
+            included => state.spent / U256::from(included),
+        };
+        let committed = state.spent.saturating_add(state.reserved);
+        if committed.saturating_add(projected) > self.budget {
+            return None;
+        }
+
+        state.reserved += projected;
+        Some(projected)
+    }
+
+    /// Replaces a reservation by the actual cost of the mint, `None` if it was not included.
+    pub(crate) fn settle(&self, reserved: U256, cost: Option<U256>) {
+        let mut state = self.state.lock().unwrap();
+
+        state.reserved = state.reserved.saturating_sub(reserved);
+        if let Some(cost) = cost {
+            state.spent = state.spent.saturating_add(cost);
+            state.included += 1;
+        }
+    }
+
+    /// Returns the budget in wei.
+    pub(crate) fn budget(&self) -> U256 {
+        self.budget
+    }
+
+    /// Returns the gas cost of the included mints so far.
+    pub(crate) fn spent(&self) -> U256 {
+        self.state.lock().unwrap().spent
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gas_budget() {
+        let budget = GasBudget::new(U256::from(1_000), U256::from(400));
+
+        // two worst cases fit, a third one would not
+        let first = budget.reserve().unwrap();
+        let second = budget.reserve().unwrap();
+        assert!(budget.reserve().is_none());
+
+        // the actual costs are lower, which frees room for more
+        budget.settle(first, Some(U256::from(100)));
+        budget.settle(second, None);
+        assert_eq!(budget.spent(), U256::from(100));
+        for _ in 0..9 {
+            let reserved = budget.reserve().unwrap();
+            assert_eq!(reserved, U256::from(100));
+            budget.settle(reserved, Some(reserved));
+        }
+        assert!(budget.reserve().is_none());
+        assert_eq!(budget.spent(), budget.budget());
+    }
+}
@@ -0,0 +1,79 @@
+use crate::executor::CallRequest;
+use alloy::{
+    dyn_abi::DynSolValue,
+    json_abi::JsonAbi,
+    primitives::{Address, U256},
+};
+use eyre::Result;
+
+/// The name of the function called when none is given.
+pub const DEFAULT_MINT_FUNCTION: &str = "mint";
+
+/// Describes the call every signer sends to mint.
+///
+/// # Fields
+///
+/// * `abi` - The JSON ABI of the contract.
+/// * `contract_address` - The address of the contract.
+/// * `function_name` - The name of the function to execute.
+/// * `args` - The arguments to pass to the function.
+/// * `value` - The amount of Ether to send with the transaction (optional).
+#[derive(Debug, Clone)]
+pub struct MintCall {
+    pub abi: JsonAbi,
+    pub contract_address: Address,
+    pub function_name: String,
+    pub args: Vec<DynSolValue>,
+    pub value: Option<U256>,
+}
+
+impl MintCall {
+    /// Creates a new `MintCall` instance calling `mint()` without arguments nor value.
+    ///
+    /// # Arguments
+    ///
+    /// * `abi` - The JSON ABI of the contract.
+    /// * `contract_address` - The address of the contract.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - A new `MintCall` instance.
+    pub fn new(abi: JsonAbi, contract_address: Address) -> Self {
+        Self {
+            abi,
+            contract_address,
+            function_name: DEFAULT_MINT_FUNCTION.to_owned(),
+            args: Vec::new(),
+            value: None,
+        }
+    }
+
+    /// Sets the name of the function to execute.
+    pub fn with_function(mut self, function_name: &str) -> Self {
+        self.function_name = function_name.to_owned();
+        self
+    }
+
+    /// Sets the arguments to pass to the function.
+    pub fn with_args(mut self, args: Vec<DynSolValue>) -> Self {
+        self.args = args;
+        self
+    }
+
+    /// Sets the amount of Ether to send with the transaction.
+    pub fn with_value(mut self, value: U256) -> Self {
+        self.value = Some(value);
+        self
+    }
+
+    /// Encodes the call.
+    pub(crate) fn encode(&self) -> Result<CallRequest> {
+        CallRequest::from_abi(
+            &self.abi,
+            self.contract_address,
+            &self.function_name,
+            &self.args,
+            self.value,
+        )
+    }
+}
@@ -0,0 +1,218 @@
+use crate::bindings::FreeMint;
+use crate::network::http_provider;
+use alloy::{
+    network::TxSigner,
+    primitives::{Address, PrimitiveSignature},
+    providers::Provider,
+    rpc::types::{Filter, Log},
+    sol_types::SolEvent,
+    transports::http::reqwest::Url,
+};
+use eyre::{eyre, Result, WrapErr};
+use serde::Deserialize;
+use std::collections::HashSet;
+use tracing::{debug, info, instrument};
+
+/// The default number of blocks queried per `eth_getLogs` request.
+pub const DEFAULT_LOG_CHUNK_SIZE: u64 = 10_000;
+
+/// Describes how to find the addresses that already minted from the mint history.
+///
+/// Mints are the `Transfer(address(0), to, …)` logs of the mint contract, so this works for
+/// any ERC-20 or ERC-721 token, even when the per-address flag is private: the recipient is
+/// read from the indexed topics only, whether the amount or token id is in the data or indexed.
+///
+/// # Fields
+///
+/// * `from_block` - The block to scan from, ideally the deployment block of the contract
+///   (optional, defaults to `0`).
+/// * `chunk_size` - The number of blocks queried per `eth_getLogs` request, to stay under the
+///   range limit of the RPC endpoint (optional, defaults to [`DEFAULT_LOG_CHUNK_SIZE`]).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct MintedScan {
+    pub from_block: u64,
+    pub chunk_size: u64,
+}
+
+impl Default for MintedScan {
+    fn default() -> Self {
+        Self {
+            from_block: 0,
+            chunk_size: DEFAULT_LOG_CHUNK_SIZE,
+        }
+    }
+}
+
+impl MintedScan {
+    /// Collects the addresses that received a mint from a contract, up to the latest block.
+    ///
+    /// # Arguments
+    ///
+    /// * `rpc_http` - The HTTP URL of the Ethereum RPC endpoint.
+    /// * `contract_address` - The address of the mint contract.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<HashSet<Address>>` - The minted addresses on success.
+    #[instrument(level = "debug", skip(self, rpc_http), fields(from_block = self.from_block))]
+    pub async fn minted_addresses(
+        &self,
+        rpc_http: Url,
+        contract_address: Address,
+    ) -> Result<HashSet<Address>> {
+        let provider = http_provider(rpc_http);
+        let latest = provider.get_block_number().await?;
+
+        let mut minted = HashSet::new();
+        for (from_block, to_block) in block_ranges(self.from_block, latest, self.chunk_size)? {
+            let filter = Filter::new()
+                .address(contract_address)
+                .event_signature(FreeMint::Transfer::SIGNATURE_HASH)
+                .topic1(Address::ZERO.into_word())
+                .from_block(from_block)
+                .to_block(to_block);
+            let logs = provider.get_logs(&filter).await.wrap_err_with(|| {
+                format!("failed to get logs of blocks {from_block}..={to_block}")
+            })?;
+
+            minted.extend(logs.iter().filter_map(minted_recipient));
+        }
+        debug!(minted = minted.len(), latest, "minted addresses collected");
+
+        Ok(minted)
+    }
+
+    /// Drops the signers that already minted from a contract.
+    ///
+    /// # Arguments
+    ///
+    /// * `signers` - The signers to filter.
+    /// * `rpc_http` - The HTTP URL of the Ethereum RPC endpoint.
+    /// * `contract_address` - The address of the mint contract.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Vec<S>>` - The signers that have not minted yet, in their original order.
+    pub async fn eligible_signers<S>(
+        &self,
+        signers: Vec<S>,
+        rpc_http: Url,
+        contract_address: Address,
+    ) -> Result<Vec<S>>
+    where
+        S: TxSigner<PrimitiveSignature>,
+    {
+        let minted = self.minted_addresses(rpc_http, contract_address).await?;
+
+        let total = signers.len();
+        let eligible: Vec<S> = signers
+            .into_iter()
+            .filter(|signer| !minted.contains(&signer.address()))
+            .collect();
+        info!(
+            eligible = eligible.len(),
+            already_minted = total - eligible.len(),
+            "signers filtered"
+        );
+
+        Ok(eligible)
+    }
+}
+
+/// Returns the recipient of a mint `Transfer` log, `None` for any other or malformed log.
+fn minted_recipient(log: &Log) -> Option<Address> {
+    match log.topics() {
+        [signature, from, to, ..]
+            if *signature == FreeMint::Transfer::SIGNATURE_HASH
+                && *from == Address::ZERO.into_word() =>
+        {
+            // indexed addresses are left-padded, anything else is not a `Transfer`
+            to[..12]
+                .iter()
+                .all(|byte| *byte == 0)
+                .then(|| Address::from_word(*to))
+        }
+        _ => None,
+    }
+}
+
+/// Splits `from..=to` into inclusive ranges of at most `chunk_size` blocks.
+fn block_ranges(from: u64, to: u64, chunk_size: u64) -> Result<Vec<(u64, u64)>> {
+    if chunk_size == 0 {
+        return Err(eyre!("the log chunk size must be positive"));
+    }
+
+    let mut ranges = Vec::new();
+    let mut start = from;
+    while start <= to {
+        let end = start.saturating_add(chunk_size - 1).min(to);
+        ranges.push((start, end));
+        if end == u64::MAX {
+            break;
+        }
+        start = end + 1;
+    }
+
+    Ok(ranges)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy::primitives::{Bytes, LogData, B256, U256};
+
+    fn log(topics: Vec<B256>, data: Bytes) -> Log {
+        Log {
+            inner: alloy::primitives::Log {
+                address: Address::repeat_byte(0xaa),
+                data: LogData::new_unchecked(topics, data),
+            },
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_minted_recipient() {
+        let to = Address::repeat_byte(1);
+        let transfer = FreeMint::Transfer::SIGNATURE_HASH;
+        let zero = Address::ZERO.into_word();
+
+        // ERC-20: the amount is in the data
+        let erc20 = log(
+            vec![transfer, zero, to.into_word()],
+            U256::from(5).to_be_bytes::<32>().into(),
+        );
+        assert_eq!(minted_recipient(&erc20), Some(to));
+
+        // ERC-721: the token id is indexed and the data is empty
+        let erc721 = log(
+            vec![transfer, zero, to.into_word(), B256::with_last_byte(7)],
+            Bytes::new(),
+        );
+        assert_eq!(minted_recipient(&erc721), Some(to));
+
+        // transfers between holders, other events and malformed logs are skipped
+        let transfer_out = log(vec![transfer, to.into_word(), zero], Bytes::new());
+        let other = log(
+            vec![B256::repeat_byte(2), zero, to.into_word()],
+            Bytes::new(),
+        );
+        let truncated = log(vec![transfer, zero], Bytes::new());
+        let dirty = log(vec![transfer, zero, B256::repeat_byte(1)], Bytes::new());
+        for log in [transfer_out, other, truncated, dirty] {
+            assert_eq!(minted_recipient(&log), None);
+        }
+    }
+
+    #[test]
+    fn test_block_ranges() {
+        assert_eq!(
+            block_ranges(0, 25, 10).unwrap(),
+            [(0, 9), (10, 19), (20, 25)]
+        );
+        assert_eq!(block_ranges(5, 5, 10).unwrap(), [(5, 5)]);
+        assert!(block_ranges(6, 5, 10).unwrap().is_empty());
+        assert!(block_ranges(0, 5, 0).is_err());
+    }
+}
@@ -0,0 +1,135 @@
+use super::MintCall;
+use crate::{bindings::FreeMint, executor::CallRequest};
+use alloy::{dyn_abi::DynSolValue, primitives::Address};
+use eyre::Result;
+
+/// Encodes the call a signer sends to mint.
+///
+/// Implemented by [`MintCall`] for arbitrary ABIs, by [`CallRequest`] for calls encoded
+/// ahead of time, e.g. from the typed bindings of [`crate::bindings`], and by
+/// [`PerSignerCall`] for arguments that depend on the signer.
+pub trait MintEncoder: Send + Sync {
+    /// Encodes the call sent by `signer`.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The index of the signer in the run.
+    /// * `signer` - The address of the signer sending the call.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<CallRequest>` - The encoded call on success.
+    fn encode_for(&self, index: usize, signer: Address) -> Result<CallRequest>;
+}
+
+impl MintEncoder for MintCall {
+    fn encode_for(&self, _index: usize, _signer: Address) -> Result<CallRequest> {
+        self.encode()
+    }
+}
+
+impl MintEncoder for CallRequest {
+    fn encode_for(&self, _index: usize, _signer: Address) -> Result<CallRequest> {
+        Ok(self.clone())
+    }
+}
+
+/// A mint call whose arguments are built for each signer.
+///
+/// Created with [`MintCall::with_args_fn`]; the `args` of the wrapped call are ignored.
+///
+/// # Fields
+///
+/// * `call` - The called function, value and contract.
+/// * `args` - Builds the arguments from the address and the index of the signer.
+#[derive(Debug, Clone)]
+pub struct PerSignerCall<F> {
+    pub call: MintCall,
+    pub args: F,
+}
+
+impl<F> MintEncoder for PerSignerCall<F>
+where
+    F: Fn(Address, usize) -> Result<Vec<DynSolValue>> + Send + Sync,
+{
+    fn encode_for(&self, index: usize, signer: Address) -> Result<CallRequest> {
+        let args = (self.args)(signer, index)?;
+
+        CallRequest::from_abi(
+            &self.call.abi,
+            self.call.contract_address,
+            &self.call.function_name,
+            &args,
+            self.call.value,
+        )
+    }
+}
+
+impl MintCall {
+    /// Builds the arguments of the call for each signer.
+    ///
+    /// # Arguments
+    ///
+    /// * `args` - Builds the arguments from the address and the index of the signer, e.g.
+    ///   `|signer, _| Ok(vec![signer.into()])` to mint to the signer itself.
+    ///
+    /// # Returns
+    ///
+    /// * `PerSignerCall<F>` - The per-signer call.
+    pub fn with_args_fn<F>(self, args: F) -> PerSignerCall<F>
+    where
+        F: Fn(Address, usize) -> Result<Vec<DynSolValue>> + Send + Sync,
+    {
+        PerSignerCall { call: self, args }
+    }
+}
+
+/// Encodes a typed call of `FreeMint.mint()`.
+///
+/// # Arguments
+///
+/// * `contract_address` - The address of the `FreeMint` contract.
+///
+/// # Returns
+///
+/// * `CallRequest` - The encoded call.
+pub fn free_mint_call(contract_address: Address) -> CallRequest {
+    CallRequest::from_call(contract_address, &FreeMint::mintCall {}, None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy::{json_abi::JsonAbi, primitives::U256};
+
+    #[test]
+    fn test_free_mint_call_matches_abi() {
+        let contract_address = Address::repeat_byte(1);
+        let abi = JsonAbi::parse(["function mint()"]).unwrap();
+
+        let dynamic = MintCall::new(abi, contract_address)
+            .encode_for(0, Address::ZERO)
+            .unwrap();
+        let typed = free_mint_call(contract_address)
+            .encode_for(0, Address::ZERO)
+            .unwrap();
+
+        assert_eq!(typed, dynamic);
+    }
+
+    #[test]
+    fn test_per_signer_call() {
+        let abi = JsonAbi::parse(["function mint(address to, uint256 quantity)"]).unwrap();
+        let call = MintCall::new(abi.clone(), Address::repeat_byte(1))
+            .with_args_fn(|signer, index| Ok(vec![signer.into(), U256::from(index).into()]));
+
+        for (index, signer) in [(0, Address::repeat_byte(2)), (3, Address::repeat_byte(3))] {
+            let expected = MintCall::new(abi.clone(), Address::repeat_byte(1))
+                .with_args(vec![signer.into(), U256::from(index).into()])
+                .encode()
+                .unwrap();
+
+            assert_eq!(call.encode_for(index, signer).unwrap(), expected);
+        }
+    }
+}
@@ -1,23 +1,61 @@
-use crate::executor::execute;
+use super::{
+    budget::GasBudget, estimate_mint_gas, mint_cost, MintCall, MintEncoder, MintOptions,
+    MintSummary, SupplyCheck, SupplySlots, DEFAULT_MINT_GAS_LIMIT,
+};
+use crate::executor::{submit, CallRequest, EventSink, Execution, TxEventKind, TxStatus};
+use crate::network::http_provider;
 use alloy::{
     dyn_abi::DynSolValue,
     json_abi::JsonAbi,
-    primitives::{Address, TxHash, U256},
-    signers::local::PrivateKeySigner,
-    transports::http::reqwest::Url,
+    network::{TransactionBuilder, TxSigner},
+    primitives::{Address, PrimitiveSignature, TxHash, U256},
+    providers::Provider,
+    rpc::types::TransactionRequest,
+    transports::{http::reqwest::Url, Transport},
+};
+use eyre::{eyre, Report, Result};
+use futures::{stream, FutureExt, StreamExt};
+use std::{
+    fmt,
+    sync::atomic::{AtomicBool, Ordering},
 };
-use eyre::{Report, Result};
+use tracing::{debug, info, info_span, instrument, Instrument};
+
+/// The error of a mint that was not attempted, so no transaction was sent for it.
+///
+/// # Fields
+///
+/// * `reason` - Why the mint was skipped.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MintSkipped {
+    pub reason: String,
+}
+
+impl fmt::Display for MintSkipped {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "mint skipped: {}", self.reason)
+    }
+}
+
+impl std::error::Error for MintSkipped {}
 
 /// Represents the result of a mint operation.
 ///
 /// # Fields
 ///
 /// * `signer` - The address of the signer who performed the mint operation.
-/// * `result` - The result of the mint operation, containing either the transaction hash on success or an error report on failure.
+/// * `result` - The result of the mint operation, containing either the transaction hash on success or an error report on failure, including a mint included but reverted.
+/// * `explorer_url` - The block explorer link of the transaction, if the network has one.
+/// * `transitions` - The statuses of the transaction from its first inclusion on, see
+///   [`Execution::transitions`].
+/// * `gas_cost` - What the transaction paid for gas, in wei; zero if it was not included.
 #[derive(Debug)]
 pub struct MintResult {
     pub signer: Address,
     pub result: Result<TxHash, Report>,
+    pub explorer_url: Option<String>,
+    pub transitions: Vec<TxStatus>,
+    pub gas_cost: U256,
 }
 
 impl MintResult {
@@ -26,13 +64,40 @@ impl MintResult {
     /// # Arguments
     ///
     /// * `signer` - The address of the signer who performed the mint operation.
-    /// * `tx` - The result of the mint operation, containing either the transaction hash on success or an error report on failure.
+    /// * `execution` - The execution of the mint operation, or an error report on failure.
     ///
     /// # Returns
     ///
     /// * `Self` - A new `MintResult` instance.
-    fn new(signer: Address, tx: Result<TxHash, Report>) -> Self {
-        Self { signer, result: tx }
+    fn new(signer: Address, execution: Result<Execution, Report>) -> Self {
+        match execution {
+            Ok(execution) => Self {
+                signer,
+                result: Ok(execution.tx_hash),
+                explorer_url: execution.explorer_url,
+                transitions: execution.transitions,
+                gas_cost: execution.gas_cost,
+            },
+            Err(report) => Self {
+                signer,
+                result: Err(report),
+                explorer_url: None,
+                transitions: Vec::new(),
+                gas_cost: U256::ZERO,
+            },
+        }
+    }
+
+    /// Creates the result of a mint that was not attempted.
+    fn skipped(signer: Address, reason: impl Into<String>) -> Self {
+        let reason = reason.into();
+        debug!(%reason, "mint skipped");
+        Self::new(signer, Err(MintSkipped { reason }.into()))
+    }
+
+    /// Returns `true` if the mint was not attempted, see [`MintSkipped`].
+    pub fn is_skipped(&self) -> bool {
+        matches!(&self.result, Err(report) if report.downcast_ref::<MintSkipped>().is_some())
     }
 }
 
@@ -40,7 +105,7 @@ impl MintResult {
 ///
 /// # Arguments
 ///
-/// * `signers` - A vector of private key signers who will perform the mint operations.
+/// * `signers` - A vector of signers who will perform the mint operations.
 /// * `rpc_http` - The HTTP URL of the Ethereum RPC endpoint.
 /// * `abi` - The JSON ABI of the contract.
 /// * `contract_address` - The address of the contract.
@@ -51,72 +116,345 @@ impl MintResult {
 /// # Returns
 ///
 /// * `Result<Vec<MintResult>>` - A vector of `MintResult` containing the results of the mint operations.
-pub async fn mint_loop(
-    signers: Vec<PrivateKeySigner>,
+#[instrument(skip(signers, rpc_http, abi, args, value), fields(signers = signers.len()))]
+pub async fn mint_loop<S>(
+    signers: Vec<S>,
     rpc_http: Url,
     abi: JsonAbi,
     contract_address: Address,
     function_name: Option<&str>,
     args: Option<&[DynSolValue]>,
     value: Option<U256>,
-) -> Result<Vec<MintResult>> {
-    let mut results: Vec<MintResult> = Vec::with_capacity(signers.len());
-    for signer in &signers {
-        // Use &signers to avoid unnecessary cloning
-        let tx = execute_mint(
-            signer.clone(),
-            rpc_http.clone(),
-            abi.clone(),
-            contract_address,
-            function_name,
-            args,
-            value,
-        )
-        .await;
-
-        results.push(MintResult::new(signer.address(), tx));
+) -> Result<Vec<MintResult>>
+where
+    S: TxSigner<PrimitiveSignature> + Clone + Send + Sync + 'static,
+{
+    let mut call = MintCall::new(abi, contract_address);
+    if let Some(function_name) = function_name {
+        call = call.with_function(function_name);
+    }
+    if let Some(args) = args {
+        call = call.with_args(args.to_vec());
     }
+    call.value = value;
 
-    Ok(results)
+    mint_loop_with_options(signers, rpc_http, &call, &MintOptions::default()).await
 }
 
-/// Executes a mint operation on an Ethereum smart contract.
+/// Mints tokens in a loop for multiple signers with the given options.
+///
+/// When a network profile is configured, the chain id of the RPC endpoint is verified once
+/// before any transaction is sent, and the whole run fails on mismatch.
+///
+/// With `check_funds`, the balance of each signer is checked right before its mint is sent,
+/// and signers that cannot afford it get an error result instead.
+///
+/// With `gas_budget`, the projected cost of each mint is reserved before it is sent and
+/// settled with the actual cost from its receipt; once a mint no longer fits in the budget,
+/// no further mint is sent and the remaining signers are skipped.
+///
+/// With `execute.events`, each signer reports its progress on the channel as it happens:
+/// skipped, submitted, confirmed or reverted, or failed.
+///
+/// With `execute.control`, a paused run holds back the mints not sent yet, and an aborted run
+/// skips them.
+///
+/// With `supply`, each mint holds one of the slots left under the cap while it is in flight
+/// and gives it back if it fails, so that concurrent mints never claim more than the cap
+/// leaves; signers finding no slot left are skipped. The supply is also read again before each
+/// mint: once the cap is reached, or a mint reverts with the exhaustion error, no further mint
+/// is sent and the remaining signers are skipped.
 ///
 /// # Arguments
 ///
-/// * `signer` - The private key signer of the account executing the transaction.
+/// * `signers` - A vector of signers who will perform the mint operations.
 /// * `rpc_http` - The HTTP URL of the Ethereum RPC endpoint.
-/// * `abi` - The JSON ABI of the contract.
-/// * `contract_address` - The address of the contract.
-/// * `function_name` - The name of the function to execute (optional, defaults to "mint").
-/// * `args` - The arguments to pass to the function (optional).
-/// * `value` - The amount of Ether to send with the transaction (optional).
+/// * `call` - The call every signer sends, a [`MintCall`] or a typed [`CallRequest`].
+/// * `options` - The options of the run.
 ///
 /// # Returns
 ///
-/// * `Result<TxHash>` - The transaction hash of the executed transaction on success.
-async fn execute_mint(
-    signer: PrivateKeySigner,
+/// * `Result<Vec<MintResult>>` - A vector of `MintResult` containing the results of the mint operations.
+///
+/// [`CallRequest`]: crate::executor::CallRequest
+#[instrument(
+    skip_all,
+    fields(signers = signers.len(), concurrency = options.concurrency)
+)]
+pub async fn mint_loop_with_options<S, C>(
+    signers: Vec<S>,
+    rpc_http: Url,
+    call: &C,
+    options: &MintOptions,
+) -> Result<Vec<MintResult>>
+where
+    S: TxSigner<PrimitiveSignature> + Clone + Send + Sync + 'static,
+    C: MintEncoder + ?Sized,
+{
+    if let Some(network) = &options.execute.network {
+        network.verify(rpc_http.clone()).await?;
+    }
+
+    // encode every call before sending anything, so that encoding errors fail the whole run
+    let requests = signers
+        .iter()
+        .enumerate()
+        .map(|(index, signer)| call.encode_for(index, signer.address()))
+        .collect::<Result<Vec<_>>>()?;
+
+    let max_fee_per_gas = if options.check_funds || options.gas_budget.is_some() {
+        let fee_strategy = options.execute.fee_strategy();
+        Some(fee_strategy.max_fee_per_gas(rpc_http.clone()).await?)
+    } else {
+        None
+    };
+    let gas_limit = options.gas_limit.unwrap_or(DEFAULT_MINT_GAS_LIMIT);
+    let budget = options.gas_budget.map(|budget| {
+        let worst_case = mint_cost(U256::ZERO, gas_limit, max_fee_per_gas.unwrap_or_default());
+        GasBudget::new(budget, worst_case)
+    });
+    let over_budget = AtomicBool::new(false);
+    let provider = http_provider(rpc_http.clone());
+
+    // the supply views are read on the contract the mints are sent to
+    let supply = match (&options.supply, requests.first()) {
+        (Some(check), Some(request)) => {
+            let status = check.status(rpc_http.clone(), request.to).await?;
+            Some((check, request.to, status))
+        }
+        _ => None,
+    };
+    let attempts = supply
+        .map(|(_, _, status)| status.remaining_mints())
+        .unwrap_or(usize::MAX);
+    let slots = SupplySlots::new(attempts);
+    let halted = AtomicBool::new(false);
+    #[cfg(feature = "metrics")]
+    crate::metrics::Metrics::global().set_accounts_remaining(signers.len());
+
+    // signers are independent, so their transactions can be in flight together;
+    // `buffered` keeps the results in the order of `signers`
+    let results: Vec<MintResult> = stream::iter(signers.iter().zip(requests).enumerate())
+        .map(|(index, (signer, request))| {
+            let (rpc_http, provider, halted, slots) =
+                (rpc_http.clone(), &provider, &halted, &slots);
+            let (budget, over_budget) = (budget.as_ref(), &over_budget);
+            let address = signer.address();
+            let events = EventSink::new(options.execute.events.as_ref(), address);
+            let skip_events = events.clone();
+            async move {
+                if halted.load(Ordering::Relaxed) {
+                    return MintResult::skipped(address, "the supply cap was reached");
+                }
+                // mints in flight together hold a slot each, so they never overshoot the cap
+                let Some(slot) = slots.take() else {
+                    return MintResult::skipped(
+                        address,
+                        format!("only {attempts} mints were left before the supply cap"),
+                    );
+                };
+
+                if let Some((check, contract_address, status)) = supply {
+                    match supply_left(
+                        check,
+                        rpc_http.clone(),
+                        contract_address,
+                        status.max_supply,
+                        status.mint_amount,
+                    )
+                    .await
+                    {
+                        Ok(true) => {}
+                        Ok(false) => {
+                            halted.store(true, Ordering::Relaxed);
+                            return MintResult::skipped(address, "the supply cap was reached");
+                        }
+                        Err(e) => {
+                            events.emit(TxEventKind::failed(&e));
+                            return MintResult::new(address, Err(e));
+                        }
+                    }
+                }
+
+                if let (true, Some(max_fee_per_gas)) = (options.check_funds, max_fee_per_gas) {
+                    let gas = estimate_mint_gas(provider, address, &request, gas_limit).await;
+                    let checked =
+                        check_funds(provider, address, request.value, gas, max_fee_per_gas).await;
+                    if let Err(e) = checked {
+                        events.emit(TxEventKind::failed(&e));
+                        return MintResult::new(address, Err(e));
+                    }
+                }
+
+                // a paused run holds the mint back before any budget is reserved for it
+                if let Some(control) = &options.execute.control {
+                    if control.checkpoint().await.is_err() {
+                        return MintResult::skipped(address, "the run was aborted");
+                    }
+                }
+
+                let reserved = match budget {
+                    Some(budget) if !over_budget.load(Ordering::Relaxed) => {
+                        match budget.reserve() {
+                            Some(reserved) => Some(reserved),
+                            None => {
+                                over_budget.store(true, Ordering::Relaxed);
+                                None
+                            }
+                        }
+                    }
+                    _ => None,
+                };
+                if let (Some(budget), None) = (budget, reserved) {
+                    return MintResult::skipped(
+                        address,
+                        format!(
+                            "the gas budget of {} wei would be exceeded ({} wei spent)",
+                            budget.budget(),
+                            budget.spent()
+                        ),
+                    );
+                }
+
+                let execution = submit(
+                    signer.clone(),
+                    rpc_http,
+                    request.clone(),
+                    &options.execute,
+                    None,
+                )
+                .await;
+                if let (Some(budget), Some(reserved)) = (budget, reserved) {
+                    let cost = execution.as_ref().ok().map(|execution| execution.gas_cost);
+                    budget.settle(reserved, cost);
+                }
+
+                // an included mint that reverted failed all the same, e.g. past the supply cap
+                let result = match execution {
+                    Ok(execution) if !execution.succeeded => {
+                        let error = revert_error(provider, address, &request, &execution).await;
+                        let mut result = MintResult::new(address, Ok(execution));
+                        result.result = Err(error);
+                        result
+                    }
+                    execution => MintResult::new(address, execution),
+                };
+                match &result.result {
+                    Ok(_) => slot.consume(),
+                    Err(e) => {
+                        if let Some((check, ..)) = supply {
+                            if check.is_exhausted_error(e) {
+                                halted.store(true, Ordering::Relaxed);
+                            }
+                        }
+                    }
+                }
+                result
+            }
+            .inspect(move |result| {
+                #[cfg(feature = "metrics")]
+                crate::metrics::Metrics::global().account_done();
+                if let Err(report) = &result.result {
+                    if let Some(skipped) = report.downcast_ref::<MintSkipped>() {
+                        skip_events.emit(TxEventKind::Skipped {
+                            reason: skipped.reason.clone(),
+                        });
+                    }
+                }
+            })
+            .instrument(info_span!("mint", signer = %address, index))
+        })
+        .buffered(options.concurrency.max(1))
+        .collect()
+        .await;
+
+    let summary = MintSummary::new(&results, gas_limit);
+    info!(
+        succeeded = summary.succeeded,
+        failed = summary.failed,
+        skipped = summary.skipped,
+        gas_saved = %summary.gas_saved,
+        gas_spent = %summary.gas_spent,
+        "mint run finished"
+    );
+
+    Ok(results)
+}
+
+/// Explains why an included mint reverted, by replaying it on the state of its block.
+///
+/// The replay fails with the revert reason, e.g. the supply cap error once it is reached; the
+/// error only says that the mint reverted if the replay succeeds or cannot be run.
+async fn revert_error<T, P>(
+    provider: &P,
+    from: Address,
+    request: &CallRequest,
+    execution: &Execution,
+) -> Report
+where
+    T: Transport + Clone,
+    P: Provider<T>,
+{
+    let reverted = format!("mint transaction {} reverted", execution.tx_hash);
+    let block_number = execution
+        .transitions
+        .iter()
+        .rev()
+        .find_map(|status| match status {
+            TxStatus::Included { block_number, .. } | TxStatus::Confirmed { block_number, .. } => {
+                Some(*block_number)
+            }
+            _ => None,
+        });
+    let tx = TransactionRequest::default()
+        .with_from(from)
+        .with_to(request.to)
+        .with_input(request.input.clone())
+        .with_value(request.value);
+
+    let replayed = match block_number {
+        Some(block_number) => provider.call(&tx).block(block_number.into()).await,
+        None => provider.call(&tx).await,
+    };
+    match replayed {
+        Err(error) => Report::new(error).wrap_err(reverted),
+        Ok(_) => eyre!(reverted),
+    }
+}
+
+/// Checks that another mint fits under the supply cap.
+async fn supply_left(
+    check: &SupplyCheck,
     rpc_http: Url,
-    abi: JsonAbi,
     contract_address: Address,
-    function_name: Option<&str>,
-    args: Option<&[DynSolValue]>,
-    value: Option<U256>,
-) -> Result<TxHash> {
-    let function_name = function_name.unwrap_or("mint");
-
-    let tx_hash = execute(
-        signer,
-        rpc_http,
-        abi,
-        contract_address,
-        function_name,
-        args.unwrap_or_default(),
-        value,
-    )
-    .await?
-    .tx_hash;
-
-    Ok(tx_hash)
+    max_supply: U256,
+    mint_amount: U256,
+) -> Result<bool> {
+    let total_supply = check.total_supply(rpc_http, contract_address).await?;
+
+    Ok(total_supply.saturating_add(mint_amount) <= max_supply)
+}
+
+/// Checks that an account can afford its mint.
+async fn check_funds<T, P>(
+    provider: &P,
+    address: Address,
+    value: U256,
+    gas_limit: u64,
+    max_fee_per_gas: u128,
+) -> Result<()>
+where
+    T: Transport + Clone,
+    P: Provider<T>,
+{
+    let cost = mint_cost(value, gas_limit, max_fee_per_gas);
+    let balance = provider.get_balance(address).await?;
+    if balance < cost {
+        return Err(eyre!(
+            "insufficient funds: balance is {balance} wei, the mint may cost {cost} wei \
+             ({value} wei of value plus {gas_limit} gas at {max_fee_per_gas} wei)"
+        ));
+    }
+
+    Ok(())
 }
@@ -1,2 +1,33 @@
 mod miner;
-pub use miner::{mint_loop, MintResult};
+pub use miner::{mint_loop, mint_loop_with_options, MintResult, MintSkipped};
+
+mod call;
+pub use call::{MintCall, DEFAULT_MINT_FUNCTION};
+
+mod encoder;
+pub use encoder::{free_mint_call, MintEncoder, PerSignerCall};
+
+mod args;
+pub use args::{
+    coerce_args, coerce_json_args, expand_args, expand_json_args, SignerArgs, INDEX_PLACEHOLDER,
+    SIGNER_PLACEHOLDER,
+};
+
+mod options;
+pub use options::MintOptions;
+
+mod price;
+pub(crate) use price::estimate_mint_gas;
+pub use price::{mint_cost, MintPrice, DEFAULT_MINT_GAS_LIMIT};
+
+mod supply;
+pub(crate) use supply::SupplySlots;
+pub use supply::{SupplyCheck, SupplyStatus};
+
+mod budget;
+
+mod summary;
+pub use summary::MintSummary;
+
+mod eligibility;
+pub use eligibility::{MintedScan, DEFAULT_LOG_CHUNK_SIZE};
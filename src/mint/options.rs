@@ -0,0 +1,31 @@
+use super::SupplyCheck;
+use crate::executor::ExecuteOptions;
+use alloy::primitives::U256;
+
+/// Options controlling a `mint_loop` run.
+///
+/// # Fields
+///
+/// * `execute` - The options every mint transaction is sent with.
+/// * `concurrency` - The maximum number of mint transactions in flight at once; `0` is
+///   treated as `1`, i.e. one signer after the other.
+/// * `check_funds` - Whether to check that each account can afford its mint, i.e. the value
+///   plus its estimated gas at the maximum fee per gas, before sending; accounts that cannot
+///   fail with the missing amount instead of sending a doomed transaction.
+/// * `gas_limit` - The gas assumed for a mint whose gas cannot be estimated, e.g. before the
+///   launch, and for the worst case of the gas budget (optional, defaults to
+///   [`DEFAULT_MINT_GAS_LIMIT`](super::DEFAULT_MINT_GAS_LIMIT)).
+/// * `supply` - How to read the supply of a capped contract (optional); when set, signers
+///   beyond the remaining mint slots are skipped, and so are all remaining signers once the
+///   cap is reached during the run.
+/// * `gas_budget` - The most the whole run may spend on gas, in wei (optional); mints that
+///   would exceed it are skipped, see [`mint_loop_with_options`](super::mint_loop_with_options).
+#[derive(Debug, Clone, Default)]
+pub struct MintOptions {
+    pub execute: ExecuteOptions,
+    pub concurrency: usize,
+    pub check_funds: bool,
+    pub gas_limit: Option<u64>,
+    pub supply: Option<SupplyCheck>,
+    pub gas_budget: Option<U256>,
+}
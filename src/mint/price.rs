@@ -0,0 +1,132 @@
+use crate::executor::{read_uint, CallRequest};
+use alloy::{
+    network::TransactionBuilder,
+    primitives::{Address, U256},
+    providers::Provider,
+    rpc::types::{state::AccountOverride, TransactionRequest},
+    transports::{http::reqwest::Url, Transport},
+};
+use eyre::{Result, WrapErr};
+use tracing::debug;
+
+/// The gas limit assumed for a mint whose gas cannot be estimated, e.g. before the launch.
+pub const DEFAULT_MINT_GAS_LIMIT: u64 = 200_000;
+
+/// The price of a payable mint.
+///
+/// # Variants
+///
+/// * `Fixed` - A known price, in wei.
+/// * `View` - A `uint256` view function of the mint contract returning the price, e.g.
+///   `mintPrice`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MintPrice {
+    Fixed(U256),
+    View(String),
+}
+
+impl MintPrice {
+    /// Resolves the price, reading the view function if needed.
+    ///
+    /// # Arguments
+    ///
+    /// * `rpc_http` - The HTTP URL of the Ethereum RPC endpoint.
+    /// * `contract_address` - The address of the mint contract.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<U256>` - The price, in wei, on success.
+    pub async fn resolve(&self, rpc_http: Url, contract_address: Address) -> Result<U256> {
+        match self {
+            Self::Fixed(price) => Ok(*price),
+            Self::View(function_name) => read_uint(rpc_http, contract_address, function_name)
+                .await
+                .wrap_err_with(|| {
+                    format!("failed to read the mint price from `{function_name}()`")
+                }),
+        }
+    }
+}
+
+/// Estimates the gas of a mint with `eth_estimateGas`.
+///
+/// The balance of `from` is overridden during the estimation, so that accounts can be
+/// estimated before they are funded; nodes without state overrides are asked without it.
+/// Mints that cannot be estimated, e.g. because they revert before the launch, are assumed to
+/// use `fallback`.
+///
+/// # Arguments
+///
+/// * `provider` - The provider of the RPC endpoint.
+/// * `from` - The account sending the mint.
+/// * `request` - The encoded mint, sent with its value.
+/// * `fallback` - The gas assumed when the estimation fails.
+///
+/// # Returns
+///
+/// * `u64` - The estimated gas, or `fallback`.
+pub(crate) async fn estimate_mint_gas<T, P>(
+    provider: &P,
+    from: Address,
+    request: &CallRequest,
+    fallback: u64,
+) -> u64
+where
+    T: Transport + Clone,
+    P: Provider<T>,
+{
+    let tx = TransactionRequest::default()
+        .with_from(from)
+        .with_to(request.to)
+        .with_input(request.input.clone())
+        .with_value(request.value);
+    let overrides = [(
+        from,
+        AccountOverride {
+            balance: Some(U256::MAX >> 1),
+            ..Default::default()
+        },
+    )]
+    .into_iter()
+    .collect();
+
+    let estimated = match provider.estimate_gas(&tx).overrides(&overrides).await {
+        Ok(gas) => Ok(gas),
+        Err(_) => provider.estimate_gas(&tx).await,
+    };
+    estimated.unwrap_or_else(|error| {
+        debug!(%from, %error, fallback, "mint gas not estimated, using the fallback");
+        fallback
+    })
+}
+
+/// Computes what an account needs to hold to send a mint.
+///
+/// # Arguments
+///
+/// * `value` - The value sent with the mint, i.e. its price.
+/// * `gas_limit` - The gas the mint may use.
+/// * `max_fee_per_gas` - The most the mint may pay per unit of gas.
+///
+/// # Returns
+///
+/// * `U256` - The worst-case cost of the mint, in wei.
+pub fn mint_cost(value: U256, gas_limit: u64, max_fee_per_gas: u128) -> U256 {
+    value + U256::from(gas_limit) * U256::from(max_fee_per_gas)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mint_cost() {
+        let price = U256::from(10u64.pow(16));
+
+        assert_eq!(mint_cost(price, 0, 30), price);
+        assert_eq!(
+            mint_cost(price, 100_000, 30_000_000_000),
+            price + U256::from(3_000_000_000_000_000u64)
+        );
+    }
+}
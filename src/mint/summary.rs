@@ -0,0 +1,90 @@
+use super::MintResult;
+use alloy::primitives::U256;
+
+/// Counts the outcomes of a `mint_loop` run.
+///
+/// # Fields
+///
+/// * `succeeded` - The number of mints included with a success status.
+/// * `failed` - The number of mints that failed, reverted ones included.
+/// * `skipped` - The number of mints left unattempted, e.g. once the supply cap was reached.
+/// * `gas_saved` - An estimate of the gas not spent on skipped mints.
+/// * `gas_spent` - What the included mints, reverted ones included, paid for gas, in wei.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MintSummary {
+    pub succeeded: usize,
+    pub failed: usize,
+    pub skipped: usize,
+    pub gas_saved: u64,
+    pub gas_spent: U256,
+}
+
+impl MintSummary {
+    /// Summarizes the results of a run.
+    ///
+    /// # Arguments
+    ///
+    /// * `results` - The results of the run.
+    /// * `gas_per_mint` - The gas a mint is expected to use, to estimate the gas saved.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The summary.
+    pub fn new(results: &[MintResult], gas_per_mint: u64) -> Self {
+        let mut summary = Self::default();
+        for result in results {
+            match &result.result {
+                Ok(_) => summary.succeeded += 1,
+                Err(_) if result.is_skipped() => summary.skipped += 1,
+                Err(_) => summary.failed += 1,
+            }
+            summary.gas_spent += result.gas_cost;
+        }
+        summary.gas_saved = gas_per_mint.saturating_mul(summary.skipped as u64);
+
+        summary
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mint::MintSkipped;
+    use alloy::primitives::{Address, TxHash};
+    use eyre::eyre;
+
+    #[test]
+    fn test_summary() {
+        let result = |result: Result<TxHash, _>| MintResult {
+            signer: Address::ZERO,
+            gas_cost: U256::from(result.is_ok() as u8 * 7),
+            result,
+            explorer_url: None,
+            transitions: Vec::new(),
+        };
+        let skipped = || {
+            Err(MintSkipped {
+                reason: "the supply cap was reached".to_owned(),
+            }
+            .into())
+        };
+        let results = [
+            result(Ok(TxHash::ZERO)),
+            result(Err(eyre!("execution reverted"))),
+            result(skipped()),
+            result(skipped()),
+        ];
+
+        let summary = MintSummary::new(&results, 50_000);
+        assert_eq!(
+            summary,
+            MintSummary {
+                succeeded: 1,
+                failed: 1,
+                skipped: 2,
+                gas_saved: 100_000,
+                gas_spent: U256::from(7),
+            }
+        );
+    }
+}
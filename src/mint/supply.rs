@@ -0,0 +1,214 @@
+use crate::executor::read_uint;
+use alloy::{
+    primitives::{Address, U256},
+    transports::http::reqwest::Url,
+};
+use eyre::{Report, Result};
+use serde::Deserialize;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Describes how to read the supply of a capped mint contract.
+///
+/// The defaults match `FreeMint`.
+///
+/// # Fields
+///
+/// * `max_supply_function` - The `uint256` view returning the supply cap, `MAX_SUPPLY` by default.
+/// * `mint_amount_function` - The `uint256` view returning the amount minted per call,
+///   `MINT_AMOUNT` by default.
+/// * `total_supply_function` - The `uint256` view returning the current supply, `totalSupply`
+///   by default.
+/// * `exhausted_error` - The revert message of a mint over the cap, `Total supply exceeded` by
+///   default.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct SupplyCheck {
+    pub max_supply_function: String,
+    pub mint_amount_function: String,
+    pub total_supply_function: String,
+    pub exhausted_error: String,
+}
+
+impl Default for SupplyCheck {
+    fn default() -> Self {
+        Self {
+            max_supply_function: "MAX_SUPPLY".to_owned(),
+            mint_amount_function: "MINT_AMOUNT".to_owned(),
+            total_supply_function: "totalSupply".to_owned(),
+            exhausted_error: "Total supply exceeded".to_owned(),
+        }
+    }
+}
+
+/// The supply of a capped mint contract at some point in time.
+///
+/// # Fields
+///
+/// * `max_supply` - The supply cap.
+/// * `total_supply` - The current supply.
+/// * `mint_amount` - The amount minted per call.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SupplyStatus {
+    pub max_supply: U256,
+    pub total_supply: U256,
+    pub mint_amount: U256,
+}
+
+impl SupplyStatus {
+    /// Returns how many mints can still succeed before the cap is reached.
+    pub fn remaining_mints(&self) -> usize {
+        let left = self.max_supply.saturating_sub(self.total_supply);
+
+        match left.checked_div(self.mint_amount) {
+            Some(remaining) => remaining.try_into().unwrap_or(usize::MAX),
+            None => usize::MAX,
+        }
+    }
+}
+
+impl SupplyCheck {
+    /// Reads the supply of a contract.
+    ///
+    /// # Arguments
+    ///
+    /// * `rpc_http` - The HTTP URL of the Ethereum RPC endpoint.
+    /// * `contract_address` - The address of the mint contract.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<SupplyStatus>` - The supply on success.
+    pub async fn status(&self, rpc_http: Url, contract_address: Address) -> Result<SupplyStatus> {
+        let (max_supply, total_supply, mint_amount) = tokio::try_join!(
+            read_uint(
+                rpc_http.clone(),
+                contract_address,
+                &self.max_supply_function
+            ),
+            read_uint(
+                rpc_http.clone(),
+                contract_address,
+                &self.total_supply_function
+            ),
+            read_uint(rpc_http, contract_address, &self.mint_amount_function),
+        )?;
+
+        Ok(SupplyStatus {
+            max_supply,
+            total_supply,
+            mint_amount,
+        })
+    }
+
+    /// Reads the current supply of a contract.
+    pub async fn total_supply(&self, rpc_http: Url, contract_address: Address) -> Result<U256> {
+        read_uint(rpc_http, contract_address, &self.total_supply_function).await
+    }
+
+    /// Returns `true` if a failed mint reverted because the cap was reached.
+    pub fn is_exhausted_error(&self, error: &Report) -> bool {
+        error
+            .chain()
+            .any(|cause| cause.to_string().contains(&self.exhausted_error))
+    }
+}
+
+/// The mint slots left under the supply cap, shared by the mints of a run.
+///
+/// A mint takes a slot before anything is checked or sent and gives it back unless it
+/// succeeds, so mints in flight together never claim more than what the cap leaves.
+#[derive(Debug)]
+pub(crate) struct SupplySlots {
+    left: AtomicUsize,
+}
+
+impl SupplySlots {
+    /// Creates the slots of a run, `usize::MAX` for an uncapped one.
+    pub(crate) fn new(left: usize) -> Self {
+        Self {
+            left: AtomicUsize::new(left),
+        }
+    }
+
+    /// Takes a slot, if any is left.
+    pub(crate) fn take(&self) -> Option<SupplySlot<'_>> {
+        self.left
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |left| {
+                left.checked_sub(1)
+            })
+            .ok()
+            .map(|_| SupplySlot {
+                slots: self,
+                used: false,
+            })
+    }
+}
+
+/// A slot taken from [`SupplySlots`], given back when dropped unless it was used.
+#[derive(Debug)]
+pub(crate) struct SupplySlot<'a> {
+    slots: &'a SupplySlots,
+    used: bool,
+}
+
+impl SupplySlot<'_> {
+    /// Keeps the slot, once its mint succeeded.
+    pub(crate) fn consume(mut self) {
+        self.used = true;
+    }
+}
+
+impl Drop for SupplySlot<'_> {
+    fn drop(&mut self) {
+        if !self.used {
+            self.slots.left.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use eyre::{eyre, WrapErr};
+
+    #[test]
+    fn test_remaining_mints() {
+        let status = |max: u64, total: u64, amount: u64| SupplyStatus {
+            max_supply: U256::from(max),
+            total_supply: U256::from(total),
+            mint_amount: U256::from(amount),
+        };
+
+        assert_eq!(status(100, 0, 10).remaining_mints(), 10);
+        assert_eq!(status(100, 35, 10).remaining_mints(), 6);
+        assert_eq!(status(100, 100, 10).remaining_mints(), 0);
+        assert_eq!(status(100, 120, 10).remaining_mints(), 0);
+        assert_eq!(status(100, 0, 0).remaining_mints(), usize::MAX);
+    }
+
+    #[test]
+    fn test_supply_slots() {
+        let slots = SupplySlots::new(2);
+
+        // two mints in flight take every slot
+        let first = slots.take().unwrap();
+        let second = slots.take().unwrap();
+        assert!(slots.take().is_none());
+
+        // a failed mint gives its slot back, a successful one keeps it
+        drop(first);
+        second.consume();
+        slots.take().unwrap().consume();
+        assert!(slots.take().is_none());
+    }
+
+    #[test]
+    fn test_is_exhausted_error() {
+        let check = SupplyCheck::default();
+        let error = Err::<(), _>(eyre!("execution reverted: Total supply exceeded"))
+            .wrap_err("failed to send the transaction")
+            .unwrap_err();
+
+        assert!(check.is_exhausted_error(&error));
+        assert!(!check.is_exhausted_error(&eyre!("Address has already minted")));
+    }
+}
@@ -0,0 +1,108 @@
+use super::http_provider;
+use alloy::{
+    eips::BlockNumberOrTag, providers::Provider, rpc::types::BlockTransactionsKind,
+    transports::http::reqwest::Url,
+};
+use eyre::{eyre, Result};
+use std::time::Duration;
+use tracing::info;
+
+/// The default interval between two polls of the base fee.
+pub const DEFAULT_GATE_POLL_INTERVAL: Duration = Duration::from_secs(12);
+
+/// Holds transactions back until the base fee is cheap enough.
+///
+/// # Fields
+///
+/// * `max_base_fee` - The highest `baseFeePerGas` of the latest block to send at, in wei.
+/// * `poll_interval` - The interval between two polls of the base fee.
+/// * `timeout` - How long to wait for a cheap block before giving up (optional, waits forever
+///   by default).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BaseFeeGate {
+    pub max_base_fee: u128,
+    pub poll_interval: Duration,
+    pub timeout: Option<Duration>,
+}
+
+impl BaseFeeGate {
+    /// Creates a new `BaseFeeGate` polling every [`DEFAULT_GATE_POLL_INTERVAL`] without timeout.
+    pub fn new(max_base_fee: u128) -> Self {
+        Self {
+            max_base_fee,
+            poll_interval: DEFAULT_GATE_POLL_INTERVAL,
+            timeout: None,
+        }
+    }
+
+    /// Sets the interval between two polls of the base fee.
+    pub fn with_poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    /// Sets how long to wait for a cheap block before giving up.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Waits until the base fee of the latest block is at most `max_base_fee`.
+    ///
+    /// # Arguments
+    ///
+    /// * `rpc_http` - The HTTP URL of the Ethereum RPC endpoint.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<u128>` - The base fee that opened the gate, or an error if the network has no
+    ///   base fee or the timeout elapses first.
+    pub async fn wait(&self, rpc_http: Url) -> Result<u128> {
+        match self.timeout {
+            Some(timeout) => tokio::time::timeout(timeout, self.poll(rpc_http))
+                .await
+                .map_err(|_| {
+                    eyre!(
+                        "the base fee stayed above {} wei for {timeout:?}",
+                        self.max_base_fee
+                    )
+                })?,
+            None => self.poll(rpc_http).await,
+        }
+    }
+
+    /// Polls the base fee until it is low enough.
+    async fn poll(&self, rpc_http: Url) -> Result<u128> {
+        let provider = http_provider(rpc_http);
+        let mut waiting = false;
+
+        loop {
+            let base_fee = provider
+                .get_block_by_number(BlockNumberOrTag::Latest, BlockTransactionsKind::Hashes)
+                .await?
+                .ok_or_else(|| eyre!("the latest block is not available"))?
+                .header
+                .base_fee_per_gas
+                .ok_or_else(|| {
+                    eyre!("the network has no base fee, it does not support EIP-1559")
+                })?;
+            let base_fee = u128::from(base_fee);
+            if base_fee <= self.max_base_fee {
+                if waiting {
+                    info!(base_fee, "base fee dropped, resuming");
+                }
+                return Ok(base_fee);
+            }
+            if !waiting {
+                info!(
+                    base_fee,
+                    max_base_fee = self.max_base_fee,
+                    "base fee too high, waiting"
+                );
+                waiting = true;
+            }
+
+            tokio::time::sleep(self.poll_interval).await;
+        }
+    }
+}
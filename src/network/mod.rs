@@ -0,0 +1,8 @@
+mod profile;
+pub use profile::{FeeStrategy, NetworkProfile, TX_HASH_PLACEHOLDER};
+
+mod gate;
+pub use gate::{BaseFeeGate, DEFAULT_GATE_POLL_INTERVAL};
+
+mod rpc;
+pub(crate) use rpc::{http_provider, rpc_client, HttpTransport};
@@ -0,0 +1,210 @@
+use super::http_provider;
+use alloy::{primitives::TxHash, providers::Provider, transports::http::reqwest::Url};
+use eyre::{eyre, Result};
+
+/// The placeholder replaced by the transaction hash in explorer URL templates.
+pub const TX_HASH_PLACEHOLDER: &str = "{tx_hash}";
+
+/// How transaction fees are priced.
+///
+/// # Variants
+///
+/// * `Eip1559` - EIP-1559 fees estimated by the node.
+/// * `Legacy` - A legacy gas price taken from `eth_gasPrice`.
+/// * `Fixed` - Fixed EIP-1559 fees, also acting as a hard cap on what a transaction may pay.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum FeeStrategy {
+    #[default]
+    Eip1559,
+    Legacy,
+    Fixed {
+        max_fee_per_gas: u128,
+        max_priority_fee_per_gas: u128,
+    },
+}
+
+impl FeeStrategy {
+    /// Returns the most a transaction may pay per unit of gas with this strategy.
+    ///
+    /// # Arguments
+    ///
+    /// * `rpc_http` - The HTTP URL of the Ethereum RPC endpoint, queried unless fees are fixed.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<u128>` - The maximum fee per gas, in wei, on success.
+    pub async fn max_fee_per_gas(&self, rpc_http: Url) -> Result<u128> {
+        let provider = http_provider(rpc_http);
+
+        match *self {
+            Self::Eip1559 => Ok(provider.estimate_eip1559_fees(None).await?.max_fee_per_gas),
+            Self::Legacy => Ok(provider.get_gas_price().await?),
+            Self::Fixed {
+                max_fee_per_gas, ..
+            } => Ok(max_fee_per_gas),
+        }
+    }
+}
+
+/// Describes the network a campaign is meant to run against.
+///
+/// # Fields
+///
+/// * `name` - A human readable name of the network.
+/// * `chain_id` - The chain id the RPC endpoint must report.
+/// * `currency_symbol` - The symbol of the native currency.
+/// * `explorer_url` - A block explorer URL template containing `{tx_hash}` (optional).
+/// * `fee_strategy` - The default fee strategy on this network.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NetworkProfile {
+    pub name: String,
+    pub chain_id: u64,
+    pub currency_symbol: String,
+    pub explorer_url: Option<String>,
+    pub fee_strategy: FeeStrategy,
+}
+
+impl NetworkProfile {
+    /// Creates a new `NetworkProfile` instance with the EIP-1559 fee strategy and no explorer.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - A human readable name of the network.
+    /// * `chain_id` - The chain id the RPC endpoint must report.
+    /// * `currency_symbol` - The symbol of the native currency.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - A new `NetworkProfile` instance.
+    pub fn new(name: &str, chain_id: u64, currency_symbol: &str) -> Self {
+        Self {
+            name: name.to_owned(),
+            chain_id,
+            currency_symbol: currency_symbol.to_owned(),
+            explorer_url: None,
+            fee_strategy: FeeStrategy::default(),
+        }
+    }
+
+    /// Sets the block explorer URL template, e.g. `https://etherscan.io/tx/{tx_hash}`.
+    pub fn with_explorer_url(mut self, explorer_url: &str) -> Self {
+        self.explorer_url = Some(explorer_url.to_owned());
+        self
+    }
+
+    /// Sets the default fee strategy.
+    pub fn with_fee_strategy(mut self, fee_strategy: FeeStrategy) -> Self {
+        self.fee_strategy = fee_strategy;
+        self
+    }
+
+    /// The Ethereum mainnet profile.
+    pub fn mainnet() -> Self {
+        Self::new("mainnet", 1, "ETH").with_explorer_url("https://etherscan.io/tx/{tx_hash}")
+    }
+
+    /// The Sepolia testnet profile.
+    pub fn sepolia() -> Self {
+        Self::new("sepolia", 11155111, "ETH")
+            .with_explorer_url("https://sepolia.etherscan.io/tx/{tx_hash}")
+    }
+
+    /// The profile of a local Anvil node.
+    pub fn anvil() -> Self {
+        Self::new("anvil", 31337, "ETH")
+    }
+
+    /// Looks up a built-in profile by name.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - One of `mainnet`, `sepolia` or `anvil`.
+    ///
+    /// # Returns
+    ///
+    /// * `Option<Self>` - The profile, if it is known.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "mainnet" => Some(Self::mainnet()),
+            "sepolia" => Some(Self::sepolia()),
+            "anvil" => Some(Self::anvil()),
+            _ => None,
+        }
+    }
+
+    /// Renders the explorer link of a transaction.
+    ///
+    /// # Arguments
+    ///
+    /// * `tx_hash` - The hash of the transaction.
+    ///
+    /// # Returns
+    ///
+    /// * `Option<String>` - The link, if the profile has an explorer URL template.
+    pub fn tx_url(&self, tx_hash: TxHash) -> Option<String> {
+        self.explorer_url
+            .as_ref()
+            .map(|template| template.replace(TX_HASH_PLACEHOLDER, &tx_hash.to_string()))
+    }
+
+    /// Checks that a chain id matches the profile.
+    ///
+    /// # Arguments
+    ///
+    /// * `chain_id` - The chain id reported by the RPC endpoint.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - An error describing the mismatch, if any.
+    pub fn check_chain_id(&self, chain_id: u64) -> Result<()> {
+        if chain_id != self.chain_id {
+            return Err(eyre!(
+                "chain id mismatch: profile `{}` expects chain id {}, but the RPC endpoint reports {}",
+                self.name,
+                self.chain_id,
+                chain_id
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Verifies that an RPC endpoint serves the chain of the profile.
+    ///
+    /// # Arguments
+    ///
+    /// * `rpc_http` - The HTTP URL of the Ethereum RPC endpoint.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - An error if `eth_chainId` does not match the profile.
+    pub async fn verify(&self, rpc_http: Url) -> Result<()> {
+        let provider = http_provider(rpc_http);
+        let chain_id = provider.get_chain_id().await?;
+
+        self.check_chain_id(chain_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_chain_id() {
+        let profile = NetworkProfile::mainnet();
+
+        assert!(profile.check_chain_id(1).is_ok());
+        let error = profile.check_chain_id(11155111).unwrap_err().to_string();
+        assert!(error.contains("expects chain id 1"));
+    }
+
+    #[test]
+    fn test_tx_url() {
+        let tx_hash = TxHash::repeat_byte(0xab);
+
+        let url = NetworkProfile::sepolia().tx_url(tx_hash).unwrap();
+        assert_eq!(url, format!("https://sepolia.etherscan.io/tx/{tx_hash}"));
+        assert!(NetworkProfile::anvil().tx_url(tx_hash).is_none());
+    }
+}
@@ -0,0 +1,36 @@
+use alloy::{
+    providers::{ProviderBuilder, RootProvider},
+    rpc::client::{ClientBuilder, RpcClient},
+    transports::http::{
+        reqwest::{Client, Url},
+        Http,
+    },
+};
+
+/// The HTTP transport of every RPC client of the crate, timed with the `metrics` feature.
+#[cfg(feature = "metrics")]
+pub(crate) type HttpTransport = crate::metrics::RpcMetricsService<Http<Client>>;
+
+/// The HTTP transport of every RPC client of the crate, timed with the `metrics` feature.
+#[cfg(not(feature = "metrics"))]
+pub(crate) type HttpTransport = Http<Client>;
+
+/// Connects to an RPC endpoint, timing every request when the `metrics` feature is enabled.
+///
+/// Every provider of the crate is built on such a client, so that the RPC latencies cover all
+/// the traffic of a run.
+pub(crate) fn rpc_client(rpc_http: Url) -> RpcClient<HttpTransport> {
+    #[cfg(feature = "metrics")]
+    let client = ClientBuilder::default()
+        .layer(crate::metrics::RpcMetricsLayer::new(&rpc_http))
+        .http(rpc_http);
+    #[cfg(not(feature = "metrics"))]
+    let client = ClientBuilder::default().http(rpc_http);
+
+    client
+}
+
+/// Builds a read-only provider on an [`rpc_client`].
+pub(crate) fn http_provider(rpc_http: Url) -> RootProvider<HttpTransport> {
+    ProviderBuilder::new().on_client(rpc_client(rpc_http))
+}
@@ -0,0 +1,308 @@
+use super::payload::{
+    auth_header, bundle_params, private_transaction_params, rpc_request, FLASHBOTS_SIGNATURE_HEADER,
+};
+use crate::network::{http_provider, HttpTransport};
+use alloy::{
+    primitives::{Bytes, TxHash},
+    providers::{Provider, RootProvider},
+    signers::local::PrivateKeySigner,
+    transports::http::reqwest::{header::CONTENT_TYPE, Client, StatusCode, Url},
+};
+use eyre::{eyre, Report, Result, WrapErr};
+use serde::Deserialize;
+use serde_json::Value;
+use std::{fmt, time::Duration};
+use tracing::warn;
+
+/// The default number of blocks a transaction is retargeted to before giving up.
+pub const DEFAULT_RELAY_BLOCKS: u64 = 25;
+
+/// The default interval between two polls of the chain while waiting for inclusion.
+pub const DEFAULT_RELAY_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// The default number of times a failed relay request is retried in a row.
+pub const DEFAULT_RELAY_RETRIES: usize = 5;
+
+/// The default delay before the first retry of a failed relay request, doubled at each retry.
+pub const DEFAULT_RELAY_RETRY_BACKOFF: Duration = Duration::from_millis(500);
+
+/// How transactions are submitted to a relay.
+///
+/// # Variants
+///
+/// * `Bundle` - `eth_sendBundle`, targeting one block at a time and retargeting the next
+///   block until the transaction is included.
+/// * `PrivateTransaction` - `eth_sendPrivateTransaction`, sent once with a maximum block,
+///   the relay retrying by itself until then.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RelayMethod {
+    #[default]
+    Bundle,
+    PrivateTransaction,
+}
+
+/// A bundle relay submitting transactions privately instead of through the public mempool.
+///
+/// # Fields
+///
+/// * `url` - The URL of the relay, e.g. `https://relay.flashbots.net`.
+/// * `auth_signer` - The key signing the [`FLASHBOTS_SIGNATURE_HEADER`]; it identifies the
+///   sender to the relay and needs no funds.
+/// * `method` - How transactions are submitted.
+/// * `max_blocks` - How many blocks a transaction is retried for before giving up.
+/// * `poll_interval` - The interval between two polls of the chain while waiting for inclusion.
+/// * `max_retries` - How many times in a row a request failing transiently (unreachable relay,
+///   rate limit or server error) is retried before giving up.
+/// * `retry_backoff` - The delay before the first retry, doubled at each following one.
+#[derive(Debug, Clone)]
+pub struct BundleRelay {
+    pub url: Url,
+    pub auth_signer: PrivateKeySigner,
+    pub method: RelayMethod,
+    pub max_blocks: u64,
+    pub poll_interval: Duration,
+    pub max_retries: usize,
+    pub retry_backoff: Duration,
+}
+
+impl BundleRelay {
+    /// Creates a new `BundleRelay` sending bundles for up to [`DEFAULT_RELAY_BLOCKS`] blocks.
+    pub fn new(url: Url, auth_signer: PrivateKeySigner) -> Self {
+        Self {
+            url,
+            auth_signer,
+            method: RelayMethod::Bundle,
+            max_blocks: DEFAULT_RELAY_BLOCKS,
+            poll_interval: DEFAULT_RELAY_POLL_INTERVAL,
+            max_retries: DEFAULT_RELAY_RETRIES,
+            retry_backoff: DEFAULT_RELAY_RETRY_BACKOFF,
+        }
+    }
+
+    /// Sets how transactions are submitted.
+    pub fn with_method(mut self, method: RelayMethod) -> Self {
+        self.method = method;
+        self
+    }
+
+    /// Sets how many blocks a transaction is retried for before giving up.
+    pub fn with_max_blocks(mut self, max_blocks: u64) -> Self {
+        self.max_blocks = max_blocks;
+        self
+    }
+
+    /// Sets the interval between two polls of the chain while waiting for inclusion.
+    pub fn with_poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    /// Sets how many times in a row a transiently failing request is retried, and the delay
+    /// before the first retry.
+    pub fn with_retries(mut self, max_retries: usize, retry_backoff: Duration) -> Self {
+        self.max_retries = max_retries;
+        self.retry_backoff = retry_backoff;
+        self
+    }
+
+    /// Sends a bundle of signed transactions targeting a single block.
+    ///
+    /// # Arguments
+    ///
+    /// * `txs` - The EIP-2718 encoded signed transactions, executed in order.
+    /// * `block_number` - The block the bundle is valid for.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Value>` - The result returned by the relay, usually the bundle hash.
+    pub async fn send_bundle(&self, txs: &[Bytes], block_number: u64) -> Result<Value> {
+        self.request("eth_sendBundle", bundle_params(txs, block_number))
+            .await
+    }
+
+    /// Sends a signed transaction the relay retries until a maximum block.
+    ///
+    /// # Arguments
+    ///
+    /// * `tx` - The EIP-2718 encoded signed transaction.
+    /// * `max_block_number` - The last block the transaction may be included in.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Value>` - The result returned by the relay, usually the transaction hash.
+    pub async fn send_private_transaction(
+        &self,
+        tx: &Bytes,
+        max_block_number: u64,
+    ) -> Result<Value> {
+        self.request(
+            "eth_sendPrivateTransaction",
+            private_transaction_params(tx, max_block_number),
+        )
+        .await
+    }
+
+    /// Submits a signed transaction through the relay and waits for its inclusion.
+    ///
+    /// Bundles missing their target block are sent again for the next one, up to
+    /// `max_blocks` blocks. Requests failing transiently are retried with an exponential
+    /// backoff, bundles targeting the next block to come.
+    ///
+    /// # Arguments
+    ///
+    /// * `rpc_http` - The HTTP URL of the Ethereum RPC endpoint, used to follow the chain.
+    /// * `tx` - The EIP-2718 encoded signed transaction.
+    /// * `tx_hash` - The hash of the transaction.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<TxHash>` - The hash of the included transaction, or an error if it was not
+    ///   included within `max_blocks` blocks or the relay kept failing.
+    pub async fn submit(&self, rpc_http: Url, tx: &Bytes, tx_hash: TxHash) -> Result<TxHash> {
+        let provider = http_provider(rpc_http);
+        let first_block = provider.get_block_number().await? + 1;
+        let last_block = first_block + self.max_blocks.max(1) - 1;
+        let mut failures = 0;
+
+        match self.method {
+            RelayMethod::Bundle => {
+                let mut target = first_block;
+                while target <= last_block {
+                    if let Err(error) = self.send_bundle(std::slice::from_ref(tx), target).await {
+                        self.retry_after(error, &mut failures).await?;
+                        // the relay may have received it before failing
+                        if self.is_included(&provider, tx_hash).await? {
+                            return Ok(tx_hash);
+                        }
+                        target = target.max(provider.get_block_number().await? + 1);
+                        continue;
+                    }
+                    failures = 0;
+
+                    let latest = self.wait_for_block(&provider, target).await?;
+                    if self.is_included(&provider, tx_hash).await? {
+                        return Ok(tx_hash);
+                    }
+                    // blocks may have passed while waiting, target the next one to come
+                    target = latest + 1;
+                }
+            }
+            RelayMethod::PrivateTransaction => {
+                while let Err(error) = self.send_private_transaction(tx, last_block).await {
+                    self.retry_after(error, &mut failures).await?;
+                    if self.is_included(&provider, tx_hash).await? {
+                        return Ok(tx_hash);
+                    }
+                }
+
+                let mut latest = first_block - 1;
+                while latest < last_block {
+                    latest = self.wait_for_block(&provider, latest + 1).await?;
+                    if self.is_included(&provider, tx_hash).await? {
+                        return Ok(tx_hash);
+                    }
+                }
+            }
+        }
+
+        Err(eyre!(
+            "transaction {tx_hash} was not included by the relay within {} blocks",
+            self.max_blocks
+        ))
+    }
+
+    /// Waits before retrying a failed request, or gives up if the failure is not transient or
+    /// too many requests failed in a row.
+    async fn retry_after(&self, error: Report, failures: &mut usize) -> Result<()> {
+        if error.downcast_ref::<TransientError>().is_none() || *failures >= self.max_retries {
+            return Err(error);
+        }
+
+        let delay = self.retry_backoff * 2u32.saturating_pow(*failures as u32);
+        *failures += 1;
+        warn!(%error, failures = *failures, ?delay, "relay request failed, retrying");
+        tokio::time::sleep(delay).await;
+
+        Ok(())
+    }
+
+    /// Posts a signed JSON-RPC request to the relay.
+    async fn request(&self, method: &str, params: Value) -> Result<Value> {
+        let body = serde_json::to_vec(&rpc_request(method, params))?;
+        let signature = auth_header(&self.auth_signer, &body).await?;
+
+        let response = Client::new()
+            .post(self.url.clone())
+            .header(CONTENT_TYPE, "application/json")
+            .header(FLASHBOTS_SIGNATURE_HEADER, signature)
+            .body(body)
+            .send()
+            .await
+            .map_err(|e| {
+                TransientError(format!("failed to reach the relay `{}`: {e}", self.url))
+            })?;
+        let status = response.status();
+        if status.is_server_error() || status == StatusCode::TOO_MANY_REQUESTS {
+            return Err(
+                TransientError(format!("`{method}` failed at the relay ({status})")).into(),
+            );
+        }
+        let content = response
+            .bytes()
+            .await
+            .map_err(|e| TransientError(format!("failed to read the relay response: {e}")))?;
+
+        let mut response: Value = serde_json::from_slice(&content).wrap_err_with(|| {
+            format!(
+                "invalid response from the relay ({status}): {}",
+                String::from_utf8_lossy(&content)
+            )
+        })?;
+        if let Some(error) = response.get("error") {
+            return Err(eyre!("`{method}` rejected by the relay: {error}"));
+        }
+
+        Ok(response["result"].take())
+    }
+
+    /// Waits until the chain reaches a block and returns the latest block number.
+    async fn wait_for_block(
+        &self,
+        provider: &RootProvider<HttpTransport>,
+        block: u64,
+    ) -> Result<u64> {
+        loop {
+            let latest = provider.get_block_number().await?;
+            if latest >= block {
+                return Ok(latest);
+            }
+            tokio::time::sleep(self.poll_interval).await;
+        }
+    }
+
+    /// Checks that a transaction was included and did not revert.
+    async fn is_included(
+        &self,
+        provider: &RootProvider<HttpTransport>,
+        tx_hash: TxHash,
+    ) -> Result<bool> {
+        match provider.get_transaction_receipt(tx_hash).await? {
+            Some(receipt) if !receipt.status() => Err(eyre!("transaction {tx_hash} reverted")),
+            Some(_) => Ok(true),
+            None => Ok(false),
+        }
+    }
+}
+
+/// A relay failure worth retrying: the relay was unreachable, rate limited or failed itself.
+#[derive(Debug)]
+struct TransientError(String);
+
+impl fmt::Display for TransientError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for TransientError {}
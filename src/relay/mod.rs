@@ -0,0 +1,8 @@
+mod payload;
+pub use payload::FLASHBOTS_SIGNATURE_HEADER;
+
+mod client;
+pub use client::{
+    BundleRelay, RelayMethod, DEFAULT_RELAY_BLOCKS, DEFAULT_RELAY_POLL_INTERVAL,
+    DEFAULT_RELAY_RETRIES, DEFAULT_RELAY_RETRY_BACKOFF,
+};
@@ -0,0 +1,97 @@
+use alloy::{
+    hex,
+    primitives::{keccak256, Bytes},
+    signers::{local::PrivateKeySigner, Signer},
+};
+use eyre::Result;
+use serde_json::{json, Value};
+
+/// The header authenticating requests to a relay, as defined by Flashbots.
+pub const FLASHBOTS_SIGNATURE_HEADER: &str = "X-Flashbots-Signature";
+
+/// Builds a JSON-RPC request with a single parameter object.
+pub(crate) fn rpc_request(method: &str, params: Value) -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": 1,
+        "method": method,
+        "params": [params],
+    })
+}
+
+/// Builds the parameters of `eth_sendBundle` targeting a block.
+pub(crate) fn bundle_params(txs: &[Bytes], block_number: u64) -> Value {
+    json!({
+        "txs": txs,
+        "blockNumber": format!("{block_number:#x}"),
+    })
+}
+
+/// Builds the parameters of `eth_sendPrivateTransaction` valid up to a block.
+pub(crate) fn private_transaction_params(tx: &Bytes, max_block_number: u64) -> Value {
+    json!({
+        "tx": tx,
+        "maxBlockNumber": format!("{max_block_number:#x}"),
+    })
+}
+
+/// Signs a request body for the [`FLASHBOTS_SIGNATURE_HEADER`].
+///
+/// The value is `<address>:<signature>`, where the signature is the EIP-191 signature of the
+/// hex-encoded keccak256 hash of the body.
+///
+/// # Arguments
+///
+/// * `signer` - The key identifying the searcher to the relay.
+/// * `body` - The serialized request body.
+///
+/// # Returns
+///
+/// * `Result<String>` - The value of the header on success.
+pub(crate) async fn auth_header(signer: &PrivateKeySigner, body: &[u8]) -> Result<String> {
+    let message = keccak256(body).to_string();
+    let signature = signer.sign_message(message.as_bytes()).await?;
+
+    Ok(format!(
+        "{}:{}",
+        signer.address(),
+        hex::encode_prefixed(signature.as_bytes())
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy::primitives::{Address, PrimitiveSignature};
+
+    #[test]
+    fn test_bundle_request() {
+        let request = rpc_request(
+            "eth_sendBundle",
+            bundle_params(&[Bytes::from_static(&[0xab, 0xcd])], 26),
+        );
+
+        assert_eq!(
+            request.to_string(),
+            r#"{"id":1,"jsonrpc":"2.0","method":"eth_sendBundle","params":[{"blockNumber":"0x1a","txs":["0xabcd"]}]}"#
+        );
+    }
+
+    #[tokio::test]
+    async fn test_auth_header() {
+        let signer = PrivateKeySigner::random();
+        let body = br#"{"method":"eth_sendBundle"}"#;
+
+        let header = auth_header(&signer, body).await.unwrap();
+        let (address, signature) = header.split_once(':').unwrap();
+        assert_eq!(address.parse::<Address>().unwrap(), signer.address());
+
+        let signature =
+            PrimitiveSignature::try_from(hex::decode(signature).unwrap().as_slice()).unwrap();
+        let message = keccak256(body).to_string();
+        assert_eq!(
+            signature.recover_address_from_msg(message).unwrap(),
+            signer.address()
+        );
+    }
+}
@@ -1,7 +1,6 @@
-use alloy::{hex, json_abi::JsonAbi};
+use alloy::json_abi::JsonAbi;
 use eyre::Result;
-use serde::Deserialize;
-use std::{env, fs};
+use stormint::artifact::load_artifact;
 
 /// Reads and parses a JSON artifact file to extract the ABI and bytecode.
 ///
@@ -16,30 +15,11 @@ use std::{env, fs};
 /// # Errors
 ///
 /// This function will return an error if:
-/// - The current directory cannot be retrieved.
-/// - The file cannot be read.
-/// - The file content cannot be parsed as JSON.
-/// - The bytecode cannot be decoded from hex.
+/// - The file cannot be read or parsed as an artifact.
+/// - The artifact has no bytecode.
 pub fn parse_artifact(path: &str) -> Result<(JsonAbi, Vec<u8>)> {
-    let current_dir = env::current_dir()?;
+    let artifact = load_artifact(path)?;
+    let bytecode = artifact.bytecode()?.to_vec();
 
-    let file = current_dir.join(path);
-    let content = fs::read_to_string(file)?;
-    let artifact: Artifact = serde_json::from_str(&content)?;
-
-    let (abi, bytecode) = (artifact.abi, artifact.bytecode.object);
-    let bytecode = hex::decode(&bytecode)?;
-
-    Ok((abi, bytecode))
-}
-
-#[derive(Debug, Deserialize)]
-struct Artifact {
-    abi: JsonAbi,
-    bytecode: Bytecode,
-}
-
-#[derive(Debug, Deserialize)]
-struct Bytecode {
-    object: String,
+    Ok((artifact.abi, bytecode))
 }
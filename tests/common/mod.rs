@@ -12,3 +12,9 @@ pub use provider::TestEnvironment;
 
 mod balance;
 pub use balance::get_token_balance;
+
+mod signer;
+pub use signer::RemoteSigner;
+
+mod relay;
+pub use relay::MockRelay;
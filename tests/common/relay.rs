@@ -0,0 +1,172 @@
+use alloy::primitives::Bytes;
+use alloy::providers::{Provider, ProviderBuilder};
+use alloy::transports::http::reqwest::Url;
+use eyre::{eyre, Result};
+use serde_json::{json, Value};
+use std::sync::{Arc, Mutex};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+
+/// A request received by the [`MockRelay`].
+///
+/// # Fields
+///
+/// * `signature` - The value of the `X-Flashbots-Signature` header.
+/// * `body` - The raw JSON body.
+#[derive(Debug, Clone)]
+pub struct RelayRequest {
+    pub signature: String,
+    pub body: String,
+}
+
+impl RelayRequest {
+    /// Parses the JSON body.
+    pub fn json(&self) -> Value {
+        serde_json::from_str(&self.body).unwrap()
+    }
+}
+
+/// A stand-in for a bundle relay in front of an anvil node.
+///
+/// The first `failures` submissions are answered with an HTTP 503, as an overloaded relay
+/// would. The next `misses` submissions are dropped, as if the bundle lost its block, and an
+/// empty block is mined instead; later submissions are forwarded to the node, which includes
+/// them.
+#[derive(Clone)]
+pub struct MockRelay {
+    pub url: Url,
+    pub requests: Arc<Mutex<Vec<RelayRequest>>>,
+}
+
+impl MockRelay {
+    /// Spawns the relay in front of the node at `rpc_http`.
+    pub async fn spawn(rpc_http: Url, misses: usize) -> Result<Self> {
+        Self::spawn_failing(rpc_http, 0, misses).await
+    }
+
+    /// Spawns a relay failing its first `failures` requests in front of the node at `rpc_http`.
+    pub async fn spawn_failing(rpc_http: Url, failures: usize, misses: usize) -> Result<Self> {
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let url = format!("http://{}", listener.local_addr()?).parse()?;
+        let requests = Arc::new(Mutex::new(Vec::new()));
+
+        let received = requests.clone();
+        tokio::spawn(async move {
+            while let Ok((stream, _)) = listener.accept().await {
+                let (rpc_http, received) = (rpc_http.clone(), received.clone());
+                tokio::spawn(async move {
+                    let _ = serve(stream, rpc_http, received, failures, misses).await;
+                });
+            }
+        });
+
+        Ok(Self { url, requests })
+    }
+
+    /// Returns the requests received so far.
+    pub fn requests(&self) -> Vec<RelayRequest> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+/// Answers a single HTTP request.
+async fn serve(
+    mut stream: TcpStream,
+    rpc_http: Url,
+    received: Arc<Mutex<Vec<RelayRequest>>>,
+    failures: usize,
+    misses: usize,
+) -> Result<()> {
+    let request = read_request(&mut stream).await?;
+    let body = request.json();
+    let attempt = {
+        let mut received = received.lock().unwrap();
+        received.push(request);
+        received.len()
+    };
+
+    if attempt <= failures {
+        stream
+            .write_all(
+                b"HTTP/1.1 503 Service Unavailable\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+            )
+            .await?;
+        return Ok(());
+    }
+
+    let params = &body["params"][0];
+    let txs: Vec<Bytes> = match body["method"].as_str() {
+        Some("eth_sendBundle") => serde_json::from_value(params["txs"].clone())?,
+        Some("eth_sendPrivateTransaction") => vec![serde_json::from_value(params["tx"].clone())?],
+        _ => return Err(eyre!("unexpected request {body}")),
+    };
+
+    let provider = ProviderBuilder::new().on_http(rpc_http);
+    if attempt <= failures + misses {
+        provider
+            .raw_request::<_, String>("evm_mine".into(), ())
+            .await?;
+    } else {
+        for tx in &txs {
+            provider
+                .send_raw_transaction(tx)
+                .await?
+                .get_receipt()
+                .await?;
+        }
+    }
+
+    let response = json!({ "jsonrpc": "2.0", "id": 1, "result": { "bundleHash": "0x01" } });
+    let response = response.to_string();
+    stream
+        .write_all(
+            format!(
+                "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{response}",
+                response.len()
+            )
+            .as_bytes(),
+        )
+        .await?;
+
+    Ok(())
+}
+
+/// Reads the signature header and the body of an HTTP request.
+async fn read_request(stream: &mut TcpStream) -> Result<RelayRequest> {
+    let mut buffer = Vec::new();
+    let mut chunk = [0u8; 4096];
+    let header_end = loop {
+        let read = stream.read(&mut chunk).await?;
+        if read == 0 {
+            return Err(eyre!("connection closed before the headers"));
+        }
+        buffer.extend_from_slice(&chunk[..read]);
+        if let Some(end) = buffer.windows(4).position(|window| window == b"\r\n\r\n") {
+            break end + 4;
+        }
+    };
+
+    let headers = String::from_utf8_lossy(&buffer[..header_end]).to_string();
+    let header = |name: &str| {
+        headers.lines().find_map(|line| {
+            let (key, value) = line.split_once(':')?;
+            key.eq_ignore_ascii_case(name)
+                .then(|| value.trim().to_owned())
+        })
+    };
+    let length: usize = header("content-length")
+        .ok_or_else(|| eyre!("missing content length"))?
+        .parse()?;
+    let signature = header("x-flashbots-signature").unwrap_or_default();
+
+    while buffer.len() < header_end + length {
+        let read = stream.read(&mut chunk).await?;
+        if read == 0 {
+            return Err(eyre!("connection closed before the body"));
+        }
+        buffer.extend_from_slice(&chunk[..read]);
+    }
+    let body = String::from_utf8(buffer[header_end..header_end + length].to_vec())?;
+
+    Ok(RelayRequest { signature, body })
+}
@@ -0,0 +1,58 @@
+use alloy::consensus::SignableTransaction;
+use alloy::network::TxSigner;
+use alloy::primitives::{Address, PrimitiveSignature, B256};
+use alloy::signers::local::PrivateKeySigner;
+use alloy::signers::SignerSync;
+use async_trait::async_trait;
+use tokio::sync::{mpsc, oneshot};
+
+type SignRequest = (
+    B256,
+    oneshot::Sender<alloy::signers::Result<PrimitiveSignature>>,
+);
+
+/// A stand-in for a remote signing service.
+///
+/// The private key lives in a background task and is never shared with the caller, which only
+/// sends signature hashes and receives signatures back, like it would over HTTP.
+#[derive(Clone)]
+pub struct RemoteSigner {
+    address: Address,
+    requests: mpsc::Sender<SignRequest>,
+}
+
+impl RemoteSigner {
+    /// Spawns the signing service for the given key and returns a client to it.
+    pub fn spawn(key: PrivateKeySigner) -> Self {
+        let address = key.address();
+        let (requests, mut receiver) = mpsc::channel::<SignRequest>(16);
+
+        tokio::spawn(async move {
+            while let Some((hash, reply)) = receiver.recv().await {
+                let _ = reply.send(key.sign_hash_sync(&hash));
+            }
+        });
+
+        Self { address, requests }
+    }
+}
+
+#[async_trait]
+impl TxSigner<PrimitiveSignature> for RemoteSigner {
+    fn address(&self) -> Address {
+        self.address
+    }
+
+    async fn sign_transaction(
+        &self,
+        tx: &mut dyn SignableTransaction<PrimitiveSignature>,
+    ) -> alloy::signers::Result<PrimitiveSignature> {
+        let (reply, response) = oneshot::channel();
+        self.requests
+            .send((tx.signature_hash(), reply))
+            .await
+            .map_err(alloy::signers::Error::other)?;
+
+        response.await.map_err(alloy::signers::Error::other)?
+    }
+}
@@ -0,0 +1,70 @@
+use crate::common::{deploy_contract, get_token_balance, parse_artifact, TestEnvironment};
+use alloy::hex;
+use alloy::primitives::U256;
+use alloy::providers::Provider;
+use eyre::Result;
+use std::env;
+use stormint::campaign::{run_campaign, CampaignConfig};
+use stormint::distributor::{deterministic_address, DISTRIBUTOR_SALT};
+
+const MNEMONIC: &str = "test test test test test test test test test test test junk";
+const MINT_ARTIFACT: &str = "contracts/out/FreeMint.sol/FreeMint.json";
+
+/// Runs the workflow of `test_workflow` from a declarative campaign.
+///
+/// No distributor is configured, so the campaign deploys the deterministic one.
+#[tokio::test]
+async fn test_campaign() -> Result<()> {
+    let test_env = TestEnvironment::try_default()?;
+    let (provider, url, signers) = (test_env.provider, test_env.url, test_env.signers);
+
+    let (abi, bytecode) = parse_artifact(MINT_ARTIFACT)?;
+    let mint_address = deploy_contract(provider.clone(), bytecode).await?;
+
+    env::set_var("CAMPAIGN_TEST_MNEMONIC", MNEMONIC);
+    env::set_var(
+        "CAMPAIGN_TEST_FUNDER_KEY",
+        hex::encode(signers[0].to_bytes()),
+    );
+
+    let campaign = format!(
+        r#"
+        [network]
+        profile = "anvil"
+        rpc_urls = ["{url}"]
+
+        [accounts]
+        mnemonic_env = "CAMPAIGN_TEST_MNEMONIC"
+        start_index = 300
+        end_index = 310
+
+        [funding]
+        sender_key_env = "CAMPAIGN_TEST_FUNDER_KEY"
+        amount = "0.001 ether"
+        batch_size = 4
+
+        [mint]
+        contract = "{mint_address}"
+        abi = "{MINT_ARTIFACT}"
+        concurrency = 4
+        "#
+    );
+    let config = CampaignConfig::from_toml_str(&campaign)?;
+
+    let report = run_campaign(&config).await?;
+    assert_eq!(report.funding.len(), 3);
+    assert!(!provider
+        .get_code_at(deterministic_address(DISTRIBUTOR_SALT))
+        .await?
+        .is_empty());
+    assert_eq!(report.results.len(), 10);
+
+    for result in report.results {
+        assert!(result.result.is_ok());
+        let balance =
+            get_token_balance(url.clone(), abi.clone(), mint_address, result.signer).await?;
+        assert!(balance > U256::ZERO);
+    }
+
+    Ok(())
+}
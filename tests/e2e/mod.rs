@@ -1 +1,2 @@
+pub mod campaign_test;
 pub mod workflow_test;
@@ -0,0 +1,108 @@
+use crate::common::{deploy_contract, get_token_balance, parse_artifact, TestEnvironment};
+use alloy::json_abi::JsonAbi;
+use alloy::primitives::{Address, B256, U256};
+use alloy::providers::Provider;
+use alloy::signers::local::PrivateKeySigner;
+use alloy::sol_types::SolValue;
+use eyre::Result;
+use stormint::allowlist::{
+    sign_voucher, voucher_domain, AllowlistCall, AllowlistTree, VoucherCall, Vouchers,
+};
+use stormint::mint::{mint_loop_with_options, MintCall, MintOptions};
+
+const ARTIFACT_PATH: &str = "contracts/out/AllowlistMint.sol/AllowlistMint.json";
+
+/// Deploys `AllowlistMint` with the given merkle root and voucher signer.
+async fn deploy_allowlist_mint(
+    test_env: &TestEnvironment,
+    root: B256,
+    voucher_signer: Address,
+) -> Result<(JsonAbi, Address)> {
+    let (abi, mut bytecode) = parse_artifact(ARTIFACT_PATH)?;
+    bytecode.extend((root, voucher_signer).abi_encode_params());
+
+    let contract_address = deploy_contract(test_env.provider.clone(), bytecode).await?;
+
+    Ok((abi, contract_address))
+}
+
+#[tokio::test]
+async fn test_allowlist_mint() -> Result<()> {
+    let test_env = TestEnvironment::new(Some(4))?;
+    let minters = test_env.signers[1..].to_vec();
+
+    let entries: Vec<(Address, U256)> = minters
+        .iter()
+        .enumerate()
+        .map(|(i, minter)| (minter.address(), U256::from(100 * (i + 1))))
+        .collect();
+    let tree = AllowlistTree::new(&entries)?;
+
+    let (abi, contract_address) =
+        deploy_allowlist_mint(&test_env, tree.root(), Address::ZERO).await?;
+
+    let call = AllowlistCall::new(
+        MintCall::new(abi.clone(), contract_address).with_function("allowlistMint"),
+        tree,
+    );
+    let results = mint_loop_with_options(
+        minters,
+        test_env.url.clone(),
+        &call,
+        &MintOptions::default(),
+    )
+    .await?;
+
+    for (result, (address, amount)) in results.into_iter().zip(entries) {
+        assert!(result.result.is_ok());
+        let balance =
+            get_token_balance(test_env.url.clone(), abi.clone(), contract_address, address).await?;
+        assert_eq!(balance, amount);
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_voucher_mint() -> Result<()> {
+    let test_env = TestEnvironment::new(Some(3))?;
+    let minters = test_env.signers[1..].to_vec();
+    let issuer = PrivateKeySigner::random();
+
+    let (abi, contract_address) =
+        deploy_allowlist_mint(&test_env, B256::ZERO, issuer.address()).await?;
+
+    let chain_id = test_env.provider.get_chain_id().await?;
+    let domain = voucher_domain("AllowlistMint", "1", chain_id, contract_address);
+    let mut vouchers = Vouchers::default();
+    for minter in &minters {
+        let voucher = sign_voucher(&issuer, &domain, minter.address(), U256::from(42)).await?;
+        vouchers.insert(minter.address(), voucher);
+    }
+
+    let call = VoucherCall::new(
+        MintCall::new(abi.clone(), contract_address).with_function("voucherMint"),
+        vouchers,
+    );
+    let results = mint_loop_with_options(
+        minters,
+        test_env.url.clone(),
+        &call,
+        &MintOptions::default(),
+    )
+    .await?;
+
+    for result in results {
+        assert!(result.result.is_ok());
+        let balance = get_token_balance(
+            test_env.url.clone(),
+            abi.clone(),
+            contract_address,
+            result.signer,
+        )
+        .await?;
+        assert_eq!(balance, U256::from(42));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,77 @@
+use crate::common::{parse_artifact, TestEnvironment};
+use alloy::primitives::{utils::parse_ether, Address, B256};
+use alloy::providers::Provider;
+use eyre::Result;
+use stormint::bindings::Distributor;
+use stormint::distributor::{
+    deploy, deploy_deterministic, deterministic_address, DISTRIBUTOR_SALT,
+};
+
+const ARTIFACT_PATH: &str = "contracts/out/Distributor.sol/Distributor.json";
+
+/// The embedded creation bytecode must be the `forge build` output of `Distributor.sol`.
+#[test]
+fn test_embedded_bytecode_matches_artifact() -> Result<()> {
+    let (_, bytecode) = parse_artifact(ARTIFACT_PATH)?;
+
+    assert!(
+        Distributor::BYTECODE[..] == bytecode[..],
+        "the embedded Distributor bytecode differs from `{ARTIFACT_PATH}`, copy its \
+         `bytecode.object` into `src/bindings/distributor.rs`"
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_deploy_refunds_residual() -> Result<()> {
+    let test_env = TestEnvironment::try_default()?;
+    let (provider, url) = (test_env.provider, test_env.url);
+    let signer = test_env.signers.first().unwrap().clone();
+
+    let contract_address = deploy(signer, url).await?;
+    let distributor = Distributor::new(contract_address, provider.clone());
+
+    let recipient = Address::repeat_byte(0x42);
+    let amount = parse_ether("0.1")?;
+    let receipt = distributor
+        .distributeEther(vec![Distributor::Transaction { recipient, amount }])
+        .value(parse_ether("1")?)
+        .send()
+        .await?
+        .get_receipt()
+        .await?;
+    assert!(receipt.status());
+
+    assert_eq!(provider.get_balance(recipient).await?, amount);
+    assert!(provider.get_balance(contract_address).await?.is_zero());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_deploy_deterministic() -> Result<()> {
+    let test_env = TestEnvironment::try_default()?;
+    let (provider, url) = (test_env.provider, test_env.url);
+    let signer = test_env.signers.first().unwrap().clone();
+
+    let contract_address =
+        deploy_deterministic(signer.clone(), url.clone(), DISTRIBUTOR_SALT).await?;
+    assert_eq!(contract_address, deterministic_address(DISTRIBUTOR_SALT));
+    assert!(!provider.get_code_at(contract_address).await?.is_empty());
+
+    // a second deployment is a no-op
+    let nonce = provider.get_transaction_count(signer.address()).await?;
+    let again = deploy_deterministic(signer.clone(), url.clone(), DISTRIBUTOR_SALT).await?;
+    assert_eq!(again, contract_address);
+    assert_eq!(
+        provider.get_transaction_count(signer.address()).await?,
+        nonce
+    );
+
+    // another salt gives another address
+    let other = deploy_deterministic(signer, url, B256::with_last_byte(1)).await?;
+    assert_ne!(other, contract_address);
+
+    Ok(())
+}
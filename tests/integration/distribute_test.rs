@@ -1,9 +1,10 @@
 use crate::common::{deploy_contract, parse_artifact, TestEnvironment};
-use alloy::primitives::utils::parse_ether;
+use alloy::json_abi::JsonAbi;
+use alloy::primitives::{utils::parse_ether, Address};
 use alloy::providers::Provider;
 use eyre::Result;
 use stormint::account::generate_accounts;
-use stormint::distributor::{distribute, DistributeParam};
+use stormint::distributor::{deploy, distribute, distributor_abi, DistributeParam};
 
 const ARTIFACT_PATH: &str = "contracts/out/Distributor.sol/Distributor.json";
 const MNEMONIC: &str = "test test test test test test test test test test test junk";
@@ -13,12 +14,33 @@ const END_INDEX: u32 = 200;
 #[tokio::test]
 async fn test_distribute() -> Result<()> {
     let test_env = TestEnvironment::try_default()?;
-    let (provider, url) = (test_env.provider, test_env.url);
-    let signer = test_env.signers.first().unwrap().clone();
 
     let (abi, bytecode) = parse_artifact(ARTIFACT_PATH)?;
 
-    let contract_address = deploy_contract(provider.clone(), bytecode).await?;
+    let contract_address = deploy_contract(test_env.provider.clone(), bytecode).await?;
+
+    distribute_and_check(&test_env, abi, contract_address).await
+}
+
+/// Runs the same distribution through the distributor embedded in the crate.
+#[tokio::test]
+async fn test_distribute_embedded() -> Result<()> {
+    let test_env = TestEnvironment::try_default()?;
+    let signer = test_env.signers.first().unwrap().clone();
+
+    let contract_address = deploy(signer, test_env.url.clone()).await?;
+
+    distribute_and_check(&test_env, distributor_abi(), contract_address).await
+}
+
+/// Distributes ether to the receiver accounts and checks their balances.
+async fn distribute_and_check(
+    test_env: &TestEnvironment,
+    abi: JsonAbi,
+    contract_address: Address,
+) -> Result<()> {
+    let (provider, url) = (&test_env.provider, &test_env.url);
+    let signer = test_env.signers.first().unwrap().clone();
 
     // generate receiver accounts
     let receivers = generate_accounts(MNEMONIC, START_INDEX, END_INDEX)?;
@@ -0,0 +1,155 @@
+use crate::common::{deploy_contract, parse_artifact, TestEnvironment};
+use alloy::primitives::U256;
+use alloy::providers::Provider;
+use eyre::Result;
+use std::time::Duration;
+use stormint::executor::{send_call, ExecuteOptions, Finality, TxStatus};
+use stormint::mint::free_mint_call;
+
+const ARTIFACT_PATH: &str = "contracts/out/FreeMint.sol/FreeMint.json";
+
+/// The first inclusion is final without a required depth.
+#[tokio::test]
+async fn test_first_inclusion() -> Result<()> {
+    let test_env = TestEnvironment::new(Some(2))?;
+    let (provider, url, signers) = (test_env.provider, test_env.url, test_env.signers);
+
+    let (_, bytecode) = parse_artifact(ARTIFACT_PATH)?;
+    let contract_address = deploy_contract(provider.clone(), bytecode).await?;
+
+    let execution = send_call(
+        signers[1].clone(),
+        url,
+        free_mint_call(contract_address),
+        &ExecuteOptions::default(),
+    )
+    .await?;
+
+    assert!(matches!(
+        execution.transitions.as_slice(),
+        [TxStatus::Included { .. }]
+    ));
+
+    Ok(())
+}
+
+/// A mint dropped by a reorg is sent again, then confirmed once deep enough.
+#[tokio::test]
+async fn test_reorg_resubmission() -> Result<()> {
+    let test_env = TestEnvironment::new(Some(2))?;
+    let (provider, url, signers) = (test_env.provider, test_env.url, test_env.signers);
+
+    let (_, bytecode) = parse_artifact(ARTIFACT_PATH)?;
+    let contract_address = deploy_contract(provider.clone(), bytecode).await?;
+
+    let snapshot: U256 = provider.raw_request("evm_snapshot".into(), ()).await?;
+    let options = ExecuteOptions {
+        finality: Some(Finality::new(3).with_poll_interval(Duration::from_millis(50))),
+        ..Default::default()
+    };
+    let send = send_call(
+        signers[1].clone(),
+        url,
+        free_mint_call(contract_address),
+        &options,
+    );
+    let reorg = async {
+        // wait for the inclusion to be seen, then drop its block
+        while provider.get_transaction_count(signers[1].address()).await? == 0 {
+            tokio::time::sleep(Duration::from_millis(50)).await;
+        }
+        tokio::time::sleep(Duration::from_millis(500)).await;
+        let reverted: bool = provider
+            .raw_request("evm_revert".into(), (snapshot,))
+            .await?;
+        assert!(reverted);
+
+        // the resubmitted mint needs blocks on top of it
+        for _ in 0..5 {
+            tokio::time::sleep(Duration::from_millis(300)).await;
+            provider
+                .raw_request::<_, String>("evm_mine".into(), ())
+                .await?;
+        }
+        Ok::<_, eyre::Report>(())
+    };
+
+    let (execution, reorged) = tokio::join!(send, reorg);
+    reorged?;
+    let execution = execution?;
+
+    let transitions = &execution.transitions;
+    assert!(matches!(transitions[0], TxStatus::Included { .. }));
+    assert!(transitions.contains(&TxStatus::Resubmitted));
+    assert!(transitions
+        .iter()
+        .any(|status| matches!(status, TxStatus::Reorged { .. })));
+    assert!(matches!(
+        execution.status(),
+        Some(TxStatus::Confirmed { confirmations, .. }) if *confirmations >= 3
+    ));
+
+    Ok(())
+}
+
+/// A mint dropped by a reorg and never included again fails once its deadline has passed.
+#[tokio::test]
+async fn test_reinclusion_deadline() -> Result<()> {
+    let test_env = TestEnvironment::new(Some(2))?;
+    let (provider, url, signers) = (test_env.provider, test_env.url, test_env.signers);
+
+    let (_, bytecode) = parse_artifact(ARTIFACT_PATH)?;
+    let contract_address = deploy_contract(provider.clone(), bytecode).await?;
+
+    let snapshot: U256 = provider.raw_request("evm_snapshot".into(), ()).await?;
+    let options = ExecuteOptions {
+        finality: Some(
+            Finality::new(3)
+                .with_poll_interval(Duration::from_millis(50))
+                .with_reinclusion_blocks(3),
+        ),
+        ..Default::default()
+    };
+    let send = send_call(
+        signers[1].clone(),
+        url,
+        free_mint_call(contract_address),
+        &options,
+    );
+    let reorg = async {
+        while provider.get_transaction_count(signers[1].address()).await? == 0 {
+            tokio::time::sleep(Duration::from_millis(50)).await;
+        }
+        tokio::time::sleep(Duration::from_millis(500)).await;
+        provider
+            .raw_request::<_, ()>("evm_setAutomine".into(), (false,))
+            .await?;
+        let reverted: bool = provider
+            .raw_request("evm_revert".into(), (snapshot,))
+            .await?;
+        assert!(reverted);
+
+        // the resubmitted mint is dropped from the pool, so it is never included again
+        tokio::time::sleep(Duration::from_millis(500)).await;
+        provider
+            .raw_request::<_, ()>("anvil_dropAllTransactions".into(), ())
+            .await?;
+        for _ in 0..10 {
+            tokio::time::sleep(Duration::from_millis(200)).await;
+            provider
+                .raw_request::<_, String>("evm_mine".into(), ())
+                .await?;
+        }
+        Ok::<_, eyre::Report>(())
+    };
+
+    let (execution, reorged) = tokio::join!(send, reorg);
+    reorged?;
+    let error = execution.unwrap_err().to_string();
+    assert!(
+        error.contains("was not included again within 3 blocks"),
+        "{error}"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,162 @@
+use crate::common::{deploy_contract, parse_artifact, TestEnvironment};
+use alloy::providers::Provider;
+use eyre::Result;
+use std::time::Duration;
+use stormint::launch::{
+    broadcast_at, mint_on_launch, prepare_mints, LaunchCondition, LaunchWatcher,
+};
+use stormint::mint::{free_mint_call, MintOptions};
+
+const ARTIFACT_PATH: &str = "contracts/out/FreeMint.sol/FreeMint.json";
+
+/// The mints are sent once `FreeMint` is deployed in the middle of the watch.
+#[tokio::test]
+async fn test_mint_on_deployment() -> Result<()> {
+    let test_env = TestEnvironment::new(Some(3))?;
+    let (provider, url, signers) = (test_env.provider, test_env.url, test_env.signers);
+
+    let deployer = signers[0].address();
+    let nonce = provider.get_transaction_count(deployer).await?;
+    let contract_address = deployer.create(nonce);
+
+    let watcher = LaunchWatcher::new(LaunchCondition::Code(contract_address))
+        .with_poll_interval(Duration::from_millis(50))
+        .with_timeout(Duration::from_secs(30));
+    let (call, options) = (free_mint_call(contract_address), MintOptions::default());
+    let watch = mint_on_launch(&watcher, signers[1..].to_vec(), url, &call, &options);
+    let deploy = async {
+        tokio::time::sleep(Duration::from_millis(200)).await;
+        let (_, bytecode) = parse_artifact(ARTIFACT_PATH)?;
+        deploy_contract(provider, bytecode).await
+    };
+
+    let (results, deployed) = tokio::join!(watch, deploy);
+    assert_eq!(deployed?, contract_address);
+    let results = results?;
+    assert_eq!(results.len(), 2);
+    assert!(results.iter().all(|result| result.result.is_ok()));
+
+    Ok(())
+}
+
+/// Pre-signed mints are only broadcast once the target block is observed.
+#[tokio::test]
+async fn test_scheduled_mint() -> Result<()> {
+    let test_env = TestEnvironment::new(Some(3))?;
+    let (provider, url, signers) = (test_env.provider, test_env.url, test_env.signers);
+
+    let (_, bytecode) = parse_artifact(ARTIFACT_PATH)?;
+    let contract_address = deploy_contract(provider.clone(), bytecode).await?;
+
+    let options = MintOptions::default();
+    let accounts = signers[1..].to_vec();
+    let prepared = prepare_mints(
+        &accounts,
+        url.clone(),
+        &free_mint_call(contract_address),
+        &options,
+    )
+    .await?;
+    assert_eq!(prepared.len(), 2);
+
+    let target_block = provider.get_block_number().await? + 2;
+    let watcher = LaunchWatcher::new(LaunchCondition::Block(target_block))
+        .with_poll_interval(Duration::from_millis(50))
+        .with_timeout(Duration::from_secs(30));
+    let broadcast = broadcast_at(&watcher, &prepared, url, &options.execute);
+    let mine = async {
+        for _ in 0..2 {
+            tokio::time::sleep(Duration::from_millis(200)).await;
+            // nothing is sent before the target block
+            for account in &accounts {
+                assert_eq!(provider.get_transaction_count(account.address()).await?, 0);
+            }
+            provider
+                .raw_request::<_, String>("evm_mine".into(), ())
+                .await?;
+        }
+        Ok::<_, eyre::Report>(())
+    };
+
+    let (results, mined) = tokio::join!(broadcast, mine);
+    mined?;
+    for (result, prepared) in results?.iter().zip(&prepared) {
+        assert_eq!(result.mint.result.as_ref().ok(), Some(&prepared.tx_hash));
+        assert!(result.block_delta.is_some_and(|delta| delta >= 1));
+    }
+
+    Ok(())
+}
+
+/// A broadcast mint never included is reported once the inclusion deadline passes, while the
+/// others keep their results.
+#[tokio::test]
+async fn test_scheduled_mint_deadline() -> Result<()> {
+    let test_env = TestEnvironment::new(Some(3))?;
+    let (provider, url, signers) = (test_env.provider, test_env.url, test_env.signers);
+
+    let (_, bytecode) = parse_artifact(ARTIFACT_PATH)?;
+    let contract_address = deploy_contract(provider.clone(), bytecode).await?;
+
+    let options = MintOptions::default();
+    let prepared = prepare_mints(
+        &signers[1..],
+        url.clone(),
+        &free_mint_call(contract_address),
+        &options,
+    )
+    .await?;
+    provider
+        .raw_request::<_, ()>("evm_setAutomine".into(), (false,))
+        .await?;
+
+    let target_block = provider.get_block_number().await? + 1;
+    let watcher = LaunchWatcher::new(LaunchCondition::Block(target_block))
+        .with_poll_interval(Duration::from_millis(50))
+        .with_timeout(Duration::from_secs(30))
+        .with_inclusion_blocks(2);
+    let broadcast = broadcast_at(&watcher, &prepared, url, &options.execute);
+    let mine = async {
+        provider
+            .raw_request::<_, String>("evm_mine".into(), ())
+            .await?;
+        // the second mint leaves the pool once broadcast, as if priced out
+        tokio::time::sleep(Duration::from_millis(500)).await;
+        provider
+            .raw_request::<_, ()>("anvil_dropTransaction".into(), (prepared[1].tx_hash,))
+            .await?;
+        for _ in 0..3 {
+            provider
+                .raw_request::<_, String>("evm_mine".into(), ())
+                .await?;
+            tokio::time::sleep(Duration::from_millis(200)).await;
+        }
+        Ok::<_, eyre::Report>(())
+    };
+
+    let (results, mined) = tokio::join!(broadcast, mine);
+    mined?;
+    let results = results?;
+    assert_eq!(
+        results[0].mint.result.as_ref().ok(),
+        Some(&prepared[0].tx_hash)
+    );
+    assert!(results[1].mint.result.is_err());
+    assert_eq!(results[1].block_delta, None);
+
+    Ok(())
+}
+
+/// Waiting for a block that is never mined gives up after the timeout.
+#[tokio::test]
+async fn test_launch_timeout() -> Result<()> {
+    let test_env = TestEnvironment::new(None)?;
+
+    let watcher = LaunchWatcher::new(LaunchCondition::Block(1_000))
+        .with_poll_interval(Duration::from_millis(50))
+        .with_timeout(Duration::from_millis(300));
+
+    assert!(watcher.wait(test_env.url).await.is_err());
+
+    Ok(())
+}
@@ -0,0 +1,68 @@
+use crate::common::{deploy_contract, parse_artifact, TestEnvironment};
+use alloy::transports::http::reqwest::Client;
+use eyre::Result;
+use stormint::metrics::{Metrics, MetricsServer};
+use stormint::mint::{free_mint_call, mint_loop_with_options, MintOptions};
+
+const ARTIFACT_PATH: &str = "contracts/out/FreeMint.sol/FreeMint.json";
+
+/// Reads a sample of the global registry, e.g. `stormint_transactions_sent_total`.
+fn sample(rendered: &str, name: &str) -> u64 {
+    rendered
+        .lines()
+        .find_map(|line| line.strip_prefix(name)?.strip_prefix(' ')?.parse().ok())
+        .unwrap_or_default()
+}
+
+/// A mint run shows up on the `/metrics` endpoint.
+#[tokio::test]
+async fn test_metrics_endpoint() -> Result<()> {
+    let test_env = TestEnvironment::new(Some(3))?;
+    let (provider, url, signers) = (test_env.provider, test_env.url, test_env.signers);
+
+    let (_, bytecode) = parse_artifact(ARTIFACT_PATH)?;
+    let contract_address = deploy_contract(provider.clone(), bytecode).await?;
+
+    let server = MetricsServer::bind("127.0.0.1:0").await?;
+    // other tests record into the same registry, so only increases are checked
+    let before = Metrics::global().render();
+
+    let results = mint_loop_with_options(
+        signers[1..].to_vec(),
+        url,
+        &free_mint_call(contract_address),
+        &MintOptions::default(),
+    )
+    .await?;
+    assert!(results.iter().all(|result| result.result.is_ok()));
+
+    let rendered = Client::new()
+        .get(format!("http://{}/metrics", server.local_addr()))
+        .send()
+        .await?
+        .error_for_status()?
+        .text()
+        .await?;
+
+    for name in [
+        "stormint_transactions_sent_total",
+        "stormint_transactions_confirmed_total",
+    ] {
+        assert!(
+            sample(&rendered, name) >= sample(&before, name) + 2,
+            "{name}"
+        );
+    }
+    assert!(sample(&rendered, "stormint_gas_spent_wei_total") > 0);
+    assert!(rendered.contains(
+        "stormint_rpc_latency_seconds_count{method=\"eth_sendRawTransaction\",endpoint=\"localhost"
+    ));
+
+    let missing = Client::new()
+        .get(format!("http://{}/other", server.local_addr()))
+        .send()
+        .await?;
+    assert_eq!(missing.status(), 404);
+
+    Ok(())
+}
@@ -1,11 +1,20 @@
 use crate::common::{deploy_contract, get_token_balance, parse_artifact, TestEnvironment};
 use alloy::dyn_abi::DynSolValue;
 use alloy::json_abi::JsonAbi;
-use alloy::primitives::{Address, U256};
+use alloy::primitives::{hex, Address, B256, U256};
+use alloy::providers::Provider;
+use alloy::signers::local::PrivateKeySigner;
 use alloy::transports::http::reqwest::Url;
 use eyre::Result;
-use stormint::executor::call;
-use stormint::mint::mint_loop;
+use serde_json::Value;
+use std::time::Duration;
+use stormint::account::NoProgress;
+use stormint::bindings::FreeMint;
+use stormint::executor::{call, event_channel, track_progress, CallRequest, TxEventKind};
+use stormint::mint::{
+    free_mint_call, mint_loop, mint_loop_with_options, MintOptions, MintSummary, MintedScan,
+    SupplyCheck,
+};
 
 const ARTIFACT_PATH: &str = "contracts/out/FreeMint.sol/FreeMint.json";
 
@@ -43,6 +52,316 @@ async fn test_mint() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn test_typed_mint() -> Result<()> {
+    let test_env = TestEnvironment::new(Some(3))?;
+    let (provider, url, signers) = (test_env.provider, test_env.url, test_env.signers);
+
+    let (_, bytecode) = parse_artifact(ARTIFACT_PATH)?;
+    let contract_address = deploy_contract(provider.clone(), bytecode).await?;
+
+    let call = free_mint_call(contract_address);
+    let results =
+        mint_loop_with_options(signers[1..].to_vec(), url, &call, &MintOptions::default()).await?;
+
+    let token = FreeMint::new(contract_address, provider);
+    let mint_amount = token.MINT_AMOUNT().call().await?._0;
+    for result in results {
+        assert!(result.result.is_ok());
+        let balance = token.balanceOf(result.signer).call().await?._0;
+        assert_eq!(balance, mint_amount);
+    }
+
+    Ok(())
+}
+
+/// Mints through the selector of `mint()` only, as for an unverified contract.
+#[tokio::test]
+async fn test_raw_calldata_mint() -> Result<()> {
+    let test_env = TestEnvironment::new(Some(3))?;
+    let (provider, url, signers) = (test_env.provider, test_env.url, test_env.signers);
+
+    let (abi, bytecode) = parse_artifact(ARTIFACT_PATH)?;
+    let contract_address = deploy_contract(provider.clone(), bytecode).await?;
+
+    let call = CallRequest::raw(contract_address, hex!("1249c58b"), None);
+    let results = mint_loop_with_options(
+        signers[1..].to_vec(),
+        url.clone(),
+        &call,
+        &MintOptions::default(),
+    )
+    .await?;
+
+    let mint_amount = get_mint_amount(url.clone(), abi.clone(), contract_address).await?;
+    for result in results {
+        assert!(result.result.is_ok());
+        let balance =
+            get_token_balance(url.clone(), abi.clone(), contract_address, result.signer).await?;
+        assert_eq!(balance, mint_amount);
+    }
+
+    Ok(())
+}
+
+/// An unfunded account fails with the missing amount instead of sending its mint.
+#[tokio::test]
+async fn test_check_funds() -> Result<()> {
+    let test_env = TestEnvironment::new(Some(2))?;
+    let (provider, url, signers) = (test_env.provider, test_env.url, test_env.signers);
+
+    let (_, bytecode) = parse_artifact(ARTIFACT_PATH)?;
+    let contract_address = deploy_contract(provider.clone(), bytecode).await?;
+
+    let unfunded = PrivateKeySigner::random();
+    let options = MintOptions {
+        check_funds: true,
+        ..Default::default()
+    };
+    let results = mint_loop_with_options(
+        vec![signers[1].clone(), unfunded.clone()],
+        url,
+        &free_mint_call(contract_address),
+        &options,
+    )
+    .await?;
+
+    assert!(results[0].result.is_ok());
+    let error = results[1].result.as_ref().unwrap_err().to_string();
+    assert!(error.contains("insufficient funds"));
+    assert_eq!(provider.get_transaction_count(unfunded.address()).await?, 0);
+
+    Ok(())
+}
+
+/// Signers beyond the remaining slots are skipped without sending anything.
+#[tokio::test]
+async fn test_supply_cap() -> Result<()> {
+    let test_env = TestEnvironment::new(Some(4))?;
+    let (provider, url, signers) = (test_env.provider, test_env.url, test_env.signers);
+
+    let (_, bytecode) = parse_artifact(ARTIFACT_PATH)?;
+    let contract_address = deploy_contract(provider.clone(), bytecode).await?;
+
+    // reading the cap from `MINT_AMOUNT` leaves a single mint slot
+    let options = MintOptions {
+        supply: Some(SupplyCheck {
+            max_supply_function: "MINT_AMOUNT".to_owned(),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    let results = mint_loop_with_options(
+        signers[1..].to_vec(),
+        url,
+        &free_mint_call(contract_address),
+        &options,
+    )
+    .await?;
+
+    let summary = MintSummary::new(&results, 100_000);
+    assert_eq!(summary.succeeded, 1);
+    assert_eq!(summary.skipped, 2);
+    assert_eq!(summary.gas_saved, 200_000);
+    for result in &results[1..] {
+        assert!(result.is_skipped());
+        assert_eq!(provider.get_transaction_count(result.signer).await?, 0);
+    }
+
+    Ok(())
+}
+
+/// A mint included but reverted past the cap gives its slot back, fails and halts the run.
+#[tokio::test]
+async fn test_reverted_mint() -> Result<()> {
+    let test_env = TestEnvironment::new(Some(2))?;
+    let (provider, url, signers) = (test_env.provider, test_env.url, test_env.signers);
+
+    let (_, bytecode) = parse_artifact(ARTIFACT_PATH)?;
+    let contract_address = deploy_contract(provider.clone(), bytecode).await?;
+    let free_mint = FreeMint::new(contract_address, provider.clone());
+
+    // `_totalSupply` (slot 2) leaves room for a single mint
+    let total_supply =
+        free_mint.MAX_SUPPLY().call().await?._0 - free_mint.MINT_AMOUNT().call().await?._0;
+    provider
+        .raw_request::<_, bool>(
+            "anvil_setStorageAt".into(),
+            (contract_address, U256::from(2), B256::from(total_supply)),
+        )
+        .await?;
+    provider
+        .raw_request::<_, ()>("evm_setAutomine".into(), (false,))
+        .await?;
+
+    let options = MintOptions {
+        supply: Some(SupplyCheck::default()),
+        ..Default::default()
+    };
+    let call = free_mint_call(contract_address);
+    let minting = mint_loop_with_options(signers[1..].to_vec(), url, &call, &options);
+    let racing = async {
+        // once the mint is pending, a better paying mint takes the last slot in the same block
+        loop {
+            tokio::time::sleep(Duration::from_millis(100)).await;
+            let status: Value = provider.raw_request("txpool_status".into(), ()).await?;
+            if status["pending"] == "0x1" {
+                break;
+            }
+        }
+        let rival = free_mint
+            .mint()
+            .gas(100_000)
+            .max_fee_per_gas(1_000_000_000_000)
+            .max_priority_fee_per_gas(100_000_000_000)
+            .send()
+            .await?;
+        provider
+            .raw_request::<_, String>("evm_mine".into(), ())
+            .await?;
+        assert!(rival.get_receipt().await?.status());
+        Ok::<_, eyre::Report>(())
+    };
+    let (results, raced) = tokio::join!(minting, racing);
+    raced?;
+    let results = results?;
+
+    let error = results[0].result.as_ref().unwrap_err();
+    assert!(SupplyCheck::default().is_exhausted_error(error));
+    assert!(results[0].gas_cost > U256::ZERO);
+    let summary = MintSummary::new(&results, 100_000);
+    assert_eq!((summary.succeeded, summary.failed), (0, 1));
+
+    Ok(())
+}
+
+/// Signers that minted in an earlier run are found from the `Transfer` logs and left out.
+#[tokio::test]
+async fn test_skip_minted() -> Result<()> {
+    let test_env = TestEnvironment::new(Some(4))?;
+    let (provider, url, signers) = (test_env.provider, test_env.url, test_env.signers);
+
+    let (_, bytecode) = parse_artifact(ARTIFACT_PATH)?;
+    let contract_address = deploy_contract(provider.clone(), bytecode).await?;
+
+    let call = free_mint_call(contract_address);
+    mint_loop_with_options(
+        signers[1..3].to_vec(),
+        url.clone(),
+        &call,
+        &MintOptions::default(),
+    )
+    .await?;
+
+    // a chunk per block exercises the chunking
+    let scan = MintedScan {
+        from_block: 0,
+        chunk_size: 1,
+    };
+    let minted = scan.minted_addresses(url.clone(), contract_address).await?;
+    assert_eq!(minted.len(), 2);
+
+    let eligible = scan
+        .eligible_signers(signers[1..].to_vec(), url.clone(), contract_address)
+        .await?;
+    assert_eq!(eligible.len(), 1);
+    assert_eq!(eligible[0].address(), signers[3].address());
+
+    let results = mint_loop_with_options(eligible, url, &call, &MintOptions::default()).await?;
+    assert!(results.iter().all(|result| result.result.is_ok()));
+
+    Ok(())
+}
+
+/// Mints stop once the gas budget would be exceeded, and what was spent is reported.
+#[tokio::test]
+async fn test_gas_budget() -> Result<()> {
+    let test_env = TestEnvironment::new(Some(5))?;
+    let (provider, url, signers) = (test_env.provider, test_env.url, test_env.signers);
+
+    let (_, bytecode) = parse_artifact(ARTIFACT_PATH)?;
+    let contract_address = deploy_contract(provider.clone(), bytecode).await?;
+    let call = free_mint_call(contract_address);
+
+    // no budget for a single mint: nothing is sent
+    let options = MintOptions {
+        gas_budget: Some(U256::from(1)),
+        ..Default::default()
+    };
+    let results =
+        mint_loop_with_options(signers[1..3].to_vec(), url.clone(), &call, &options).await?;
+    assert!(results.iter().all(|result| result.is_skipped()));
+    for result in &results {
+        assert_eq!(provider.get_transaction_count(result.signer).await?, 0);
+    }
+
+    // an ample budget: everything is sent and the spending adds up
+    let options = MintOptions {
+        gas_budget: Some(U256::MAX),
+        ..Default::default()
+    };
+    let results = mint_loop_with_options(signers[3..].to_vec(), url, &call, &options).await?;
+    let summary = MintSummary::new(&results, 0);
+    assert_eq!(summary.succeeded, 2);
+    assert!(summary.gas_spent > U256::ZERO);
+    assert_eq!(
+        summary.gas_spent,
+        results.iter().map(|result| result.gas_cost).sum::<U256>()
+    );
+
+    Ok(())
+}
+
+/// Every signer reports its mint as it happens, ending with a single final event.
+#[tokio::test]
+async fn test_events() -> Result<()> {
+    let test_env = TestEnvironment::new(Some(4))?;
+    let (provider, url, signers) = (test_env.provider, test_env.url, test_env.signers);
+
+    let (_, bytecode) = parse_artifact(ARTIFACT_PATH)?;
+    let contract_address = deploy_contract(provider.clone(), bytecode).await?;
+    let call = free_mint_call(contract_address);
+
+    let (sender, receiver) = event_channel();
+    let mut options = MintOptions::default();
+    options.execute.events = Some(sender);
+    let results =
+        mint_loop_with_options(signers[1..3].to_vec(), url.clone(), &call, &options).await?;
+
+    // a budget too small for any mint skips the last signer
+    options.gas_budget = Some(U256::from(1));
+    mint_loop_with_options(signers[3..].to_vec(), url, &call, &options).await?;
+    drop(options);
+
+    let events = track_progress(receiver, 3, &NoProgress).await;
+    for result in &results {
+        let kinds: Vec<_> = events
+            .iter()
+            .filter(|event| event.signer == result.signer)
+            .map(|event| &event.kind)
+            .collect();
+        let tx_hash = *result.result.as_ref().unwrap();
+
+        assert!(matches!(
+            kinds.as_slice(),
+            [
+                TxEventKind::Submitted { tx_hash: submitted },
+                TxEventKind::Confirmed { tx_hash: confirmed, .. },
+            ] if *submitted == tx_hash && *confirmed == tx_hash
+        ));
+    }
+    assert!(events
+        .iter()
+        .any(|event| event.signer == signers[3].address()
+            && matches!(event.kind, TxEventKind::Skipped { .. })));
+    assert_eq!(
+        events.iter().filter(|event| event.kind.is_final()).count(),
+        3
+    );
+
+    Ok(())
+}
+
 async fn get_mint_amount(url: Url, abi: JsonAbi, contract_address: Address) -> Result<U256> {
     let mint_amount = call(url, abi, contract_address, "MINT_AMOUNT", &[]).await?;
 
@@ -1,2 +1,11 @@
+pub mod allowlist_test;
+pub mod deploy_test;
 pub mod distribute_test;
+pub mod finality_test;
+pub mod launch_test;
+#[cfg(feature = "metrics")]
+pub mod metrics_test;
 pub mod mint_test;
+pub mod network_test;
+pub mod relay_test;
+pub mod signer_test;
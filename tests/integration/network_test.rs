@@ -0,0 +1,133 @@
+use crate::common::{deploy_contract, parse_artifact, TestEnvironment};
+use alloy::primitives::U256;
+use alloy::providers::Provider;
+use alloy::transports::Transport;
+use eyre::Result;
+use std::time::Duration;
+use stormint::executor::{send_call, ExecuteOptions};
+use stormint::mint::{free_mint_call, mint_loop_with_options, MintCall, MintOptions};
+use stormint::network::{BaseFeeGate, NetworkProfile};
+
+const ARTIFACT_PATH: &str = "contracts/out/FreeMint.sol/FreeMint.json";
+
+#[tokio::test]
+async fn test_chain_id_guard() -> Result<()> {
+    let test_env = TestEnvironment::new(Some(2))?;
+    let (provider, url, signers) = (test_env.provider, test_env.url, test_env.signers);
+
+    let (abi, bytecode) = parse_artifact(ARTIFACT_PATH)?;
+    let contract_address = deploy_contract(provider.clone(), bytecode).await?;
+    let call = MintCall::new(abi, contract_address);
+
+    // a mainnet campaign pointed at the local node must not send anything
+    let options = MintOptions {
+        execute: ExecuteOptions {
+            network: Some(NetworkProfile::mainnet()),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let nonce_before = provider.get_transaction_count(signers[1].address()).await?;
+    let error = mint_loop_with_options(vec![signers[1].clone()], url.clone(), &call, &options)
+        .await
+        .unwrap_err();
+    assert!(error.to_string().contains("chain id mismatch"));
+    let nonce_after = provider.get_transaction_count(signers[1].address()).await?;
+    assert_eq!(nonce_before, nonce_after);
+
+    // the matching profile goes through
+    let options = MintOptions {
+        execute: ExecuteOptions {
+            network: Some(NetworkProfile::anvil()),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let results =
+        mint_loop_with_options(vec![signers[1].clone()], url.clone(), &call, &options).await?;
+    assert!(results[0].result.is_ok());
+
+    Ok(())
+}
+
+/// Sets the base fee of the next block and mines it.
+async fn set_base_fee<T: Transport + Clone>(
+    provider: &impl Provider<T>,
+    base_fee: u128,
+) -> Result<()> {
+    provider
+        .raw_request::<_, ()>(
+            "anvil_setNextBlockBaseFeePerGas".into(),
+            (U256::from(base_fee),),
+        )
+        .await?;
+    provider
+        .raw_request::<_, String>("evm_mine".into(), ())
+        .await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_base_fee_gate() -> Result<()> {
+    let test_env = TestEnvironment::new(Some(2))?;
+    let (provider, url, signers) = (test_env.provider, test_env.url, test_env.signers);
+
+    let (_, bytecode) = parse_artifact(ARTIFACT_PATH)?;
+    let contract_address = deploy_contract(provider.clone(), bytecode).await?;
+
+    // the mint waits while the base fee is above 10 gwei and goes out once it drops
+    set_base_fee(&provider, 50_000_000_000).await?;
+    let options = ExecuteOptions {
+        base_fee_gate: Some(
+            BaseFeeGate::new(10_000_000_000).with_poll_interval(Duration::from_millis(50)),
+        ),
+        ..Default::default()
+    };
+    let send = send_call(
+        signers[1].clone(),
+        url.clone(),
+        free_mint_call(contract_address),
+        &options,
+    );
+    let drop_fee = async {
+        tokio::time::sleep(Duration::from_millis(500)).await;
+        let nonce = provider.get_transaction_count(signers[1].address()).await?;
+        set_base_fee(&provider, 1_000_000_000).await?;
+        Ok::<_, eyre::Report>(nonce)
+    };
+
+    let (execution, nonce) = tokio::join!(send, drop_fee);
+    assert_eq!(nonce?, 0);
+    execution?;
+    assert_eq!(
+        provider.get_transaction_count(signers[1].address()).await?,
+        1
+    );
+
+    // a base fee that never drops gives up after the timeout without sending
+    set_base_fee(&provider, 50_000_000_000).await?;
+    let options = ExecuteOptions {
+        base_fee_gate: Some(
+            BaseFeeGate::new(10_000_000_000)
+                .with_poll_interval(Duration::from_millis(50))
+                .with_timeout(Duration::from_millis(300)),
+        ),
+        ..Default::default()
+    };
+    let error = send_call(
+        signers[1].clone(),
+        url,
+        free_mint_call(contract_address),
+        &options,
+    )
+    .await
+    .unwrap_err();
+
+    assert!(error.to_string().contains("base fee"));
+    assert_eq!(
+        provider.get_transaction_count(signers[1].address()).await?,
+        1
+    );
+
+    Ok(())
+}
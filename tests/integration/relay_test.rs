@@ -0,0 +1,157 @@
+use crate::common::{deploy_contract, parse_artifact, MockRelay, TestEnvironment};
+use alloy::hex;
+use alloy::primitives::{keccak256, PrimitiveSignature};
+use alloy::providers::Provider;
+use alloy::signers::local::PrivateKeySigner;
+use eyre::Result;
+use std::time::Duration;
+use stormint::bindings::FreeMint;
+use stormint::mint::{free_mint_call, mint_loop_with_options, MintOptions};
+use stormint::relay::{BundleRelay, RelayMethod};
+
+const ARTIFACT_PATH: &str = "contracts/out/FreeMint.sol/FreeMint.json";
+
+/// A bundle missing its block is retargeted to the next one until it is included.
+#[tokio::test]
+async fn test_bundle_retargeting() -> Result<()> {
+    let test_env = TestEnvironment::new(Some(2))?;
+    let (provider, url, signers) = (test_env.provider, test_env.url, test_env.signers);
+
+    let (_, bytecode) = parse_artifact(ARTIFACT_PATH)?;
+    let contract_address = deploy_contract(provider.clone(), bytecode).await?;
+
+    let mock = MockRelay::spawn(url.clone(), 2).await?;
+    let auth_signer = PrivateKeySigner::random();
+    let mut options = MintOptions::default();
+    options.execute.relay = Some(
+        BundleRelay::new(mock.url.clone(), auth_signer.clone())
+            .with_poll_interval(Duration::from_millis(50)),
+    );
+
+    let start_block = provider.get_block_number().await?;
+    let results = mint_loop_with_options(
+        signers[1..].to_vec(),
+        url,
+        &free_mint_call(contract_address),
+        &options,
+    )
+    .await?;
+    assert!(results[0].result.is_ok());
+
+    let token = FreeMint::new(contract_address, provider);
+    assert!(token.balanceOf(signers[1].address()).call().await?._0 > Default::default());
+
+    let requests = mock.requests();
+    assert_eq!(requests.len(), 3);
+    for (i, request) in requests.iter().enumerate() {
+        let body = request.json();
+        assert_eq!(body["method"], "eth_sendBundle");
+        assert_eq!(
+            body["params"][0]["blockNumber"],
+            format!("{:#x}", start_block + 1 + i as u64)
+        );
+        assert_eq!(body["params"][0]["txs"].as_array().unwrap().len(), 1);
+
+        // the header is signed by the auth key over the exact body
+        let (address, signature) = request.signature.split_once(':').unwrap();
+        assert_eq!(
+            address.parse::<alloy::primitives::Address>()?,
+            auth_signer.address()
+        );
+        let signature = PrimitiveSignature::try_from(hex::decode(signature)?.as_slice())?;
+        let message = keccak256(request.body.as_bytes()).to_string();
+        assert_eq!(
+            signature.recover_address_from_msg(message)?,
+            auth_signer.address()
+        );
+    }
+
+    Ok(())
+}
+
+/// Requests failing at the relay are retried, targeting the next block to come.
+#[tokio::test]
+async fn test_relay_retries() -> Result<()> {
+    let test_env = TestEnvironment::new(Some(2))?;
+    let (provider, url, signers) = (test_env.provider, test_env.url, test_env.signers);
+
+    let (_, bytecode) = parse_artifact(ARTIFACT_PATH)?;
+    let contract_address = deploy_contract(provider.clone(), bytecode).await?;
+
+    let mock = MockRelay::spawn_failing(url.clone(), 2, 0).await?;
+    let mut options = MintOptions::default();
+    options.execute.relay = Some(
+        BundleRelay::new(mock.url.clone(), PrivateKeySigner::random())
+            .with_poll_interval(Duration::from_millis(50))
+            .with_retries(2, Duration::from_millis(10)),
+    );
+
+    let results = mint_loop_with_options(
+        signers[1..].to_vec(),
+        url.clone(),
+        &free_mint_call(contract_address),
+        &options,
+    )
+    .await?;
+    assert!(results[0].result.is_ok());
+    assert_eq!(mock.requests().len(), 3);
+
+    // a relay failing more often than allowed fails the mint
+    let mock = MockRelay::spawn_failing(url.clone(), 3, 0).await?;
+    options.execute.relay = Some(
+        BundleRelay::new(mock.url.clone(), PrivateKeySigner::random())
+            .with_retries(2, Duration::from_millis(10)),
+    );
+    let results = mint_loop_with_options(
+        signers[..1].to_vec(),
+        url,
+        &free_mint_call(contract_address),
+        &options,
+    )
+    .await?;
+    let error = results[0].result.as_ref().unwrap_err().to_string();
+    assert!(error.contains("503"), "{error}");
+    assert_eq!(mock.requests().len(), 3);
+
+    Ok(())
+}
+
+/// A private transaction is sent once, valid up to the last block it may be retried for.
+#[tokio::test]
+async fn test_private_transaction() -> Result<()> {
+    let test_env = TestEnvironment::new(Some(2))?;
+    let (provider, url, signers) = (test_env.provider, test_env.url, test_env.signers);
+
+    let (_, bytecode) = parse_artifact(ARTIFACT_PATH)?;
+    let contract_address = deploy_contract(provider.clone(), bytecode).await?;
+
+    let mock = MockRelay::spawn(url.clone(), 0).await?;
+    let mut options = MintOptions::default();
+    options.execute.relay = Some(
+        BundleRelay::new(mock.url.clone(), PrivateKeySigner::random())
+            .with_method(RelayMethod::PrivateTransaction)
+            .with_max_blocks(5)
+            .with_poll_interval(Duration::from_millis(50)),
+    );
+
+    let start_block = provider.get_block_number().await?;
+    let results = mint_loop_with_options(
+        signers[1..].to_vec(),
+        url,
+        &free_mint_call(contract_address),
+        &options,
+    )
+    .await?;
+    assert!(results[0].result.is_ok());
+
+    let requests = mock.requests();
+    assert_eq!(requests.len(), 1);
+    let body = requests[0].json();
+    assert_eq!(body["method"], "eth_sendPrivateTransaction");
+    assert_eq!(
+        body["params"][0]["maxBlockNumber"],
+        format!("{:#x}", start_block + 5)
+    );
+
+    Ok(())
+}
@@ -0,0 +1,45 @@
+use crate::common::{
+    deploy_contract, get_token_balance, parse_artifact, RemoteSigner, TestEnvironment,
+};
+use alloy::primitives::U256;
+use eyre::Result;
+use std::sync::Arc;
+use stormint::executor::DynSigner;
+use stormint::mint::mint_loop;
+
+const ARTIFACT_PATH: &str = "contracts/out/FreeMint.sol/FreeMint.json";
+
+#[tokio::test]
+async fn test_mint_with_mixed_signers() -> Result<()> {
+    let test_env = TestEnvironment::new(Some(3))?;
+    let (provider, url, signers) = (test_env.provider, test_env.url, test_env.signers);
+
+    let (abi, bytecode) = parse_artifact(ARTIFACT_PATH)?;
+    let contract_address = deploy_contract(provider.clone(), bytecode).await?;
+
+    // a local key and a key only reachable through the signing service
+    let accounts: Vec<DynSigner> = vec![
+        Arc::new(signers[1].clone()),
+        Arc::new(RemoteSigner::spawn(signers[2].clone())),
+    ];
+
+    let results = mint_loop(
+        accounts,
+        url.clone(),
+        abi.clone(),
+        contract_address,
+        None,
+        None,
+        None,
+    )
+    .await?;
+
+    for result in results {
+        assert!(result.result.is_ok());
+        let balance =
+            get_token_balance(url.clone(), abi.clone(), contract_address, result.signer).await?;
+        assert!(balance > U256::ZERO);
+    }
+
+    Ok(())
+}